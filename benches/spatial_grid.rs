@@ -0,0 +1,96 @@
+// cargo bench --bench spatial_grid
+//
+// moves 10k balls around a large arena and finds every touching pair each
+// tick through the spatial grid, the way the collision systems use it.
+// prints the time per tick next to the 60 Hz budget, and what checking
+// every pair would cost for the same balls
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use ball_game::game::physics::resources::SpatialGrid;
+
+const BALLS: usize = 10_000;
+const TICKS: u32 = 300;
+const ARENA_SIZE: f32 = 4000.0;
+const RADIUS: f32 = 8.0;
+const SPEED: f32 = 200.0;
+const CELL_SIZE: f32 = 32.0;
+const DELTA: f32 = 1.0 / 60.0;
+
+struct Ball {
+    position: Vec2,
+    velocity: Vec2,
+}
+
+fn main() {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    let mut balls: Vec<Ball> = (0..BALLS).map(|_| Ball {
+        position: Vec2::new(rng.gen::<f32>(), rng.gen::<f32>()) * ARENA_SIZE,
+        velocity: Vec2::from_angle(rng.gen::<f32>() * std::f32::consts::TAU) * SPEED,
+    }).collect();
+    let mut grid = SpatialGrid::new(CELL_SIZE);
+
+    let mut grid_time = Duration::ZERO;
+    let mut pairs = 0;
+    (0..TICKS).for_each(|_| {
+        move_balls(&mut balls);
+
+        let start = Instant::now();
+        pairs += touching_pairs(&mut grid, &balls);
+        grid_time += start.elapsed();
+    });
+
+    let start = Instant::now();
+    let brute_force_pairs = touching_pairs_brute_force(&balls);
+    let brute_force_time = start.elapsed();
+    let grid_pairs = touching_pairs(&mut grid, &balls);
+    assert_eq!(grid_pairs, brute_force_pairs, "the grid missed or invented pairs");
+
+    let per_tick = grid_time / TICKS;
+    println!("{} balls, {} ticks, {:.1} touching pairs per tick",
+            BALLS, TICKS, pairs as f64 / TICKS as f64);
+    println!("spatial grid: {:?} per tick, {:.1}% of a 60 Hz tick",
+            per_tick, per_tick.as_secs_f64() * 60.0 * 100.0);
+    println!("every pair:   {:?} per tick", brute_force_time);
+}
+
+fn move_balls(balls: &mut [Ball]) {
+    balls.iter_mut().for_each(|ball| {
+        ball.position += ball.velocity * DELTA;
+        if !(0.0..ARENA_SIZE).contains(&ball.position.x) {
+            ball.velocity.x *= -1.0;
+        }
+        if !(0.0..ARENA_SIZE).contains(&ball.position.y) {
+            ball.velocity.y *= -1.0;
+        }
+    });
+}
+
+// rebuilt from scratch every tick like the game does
+fn touching_pairs(grid: &mut SpatialGrid, balls: &[Ball]) -> usize {
+    grid.clear();
+    balls.iter().enumerate().for_each(|(index, ball)| {
+        grid.insert(Entity::from_raw(index as u32), ball.position, RADIUS);
+    });
+
+    balls.iter().enumerate()
+        .map(|(index, ball)| {
+            grid.query(ball.position, RADIUS)
+                .filter(|entry| entry.entity.index() as usize > index)
+                .count()
+        })
+        .sum()
+}
+
+fn touching_pairs_brute_force(balls: &[Ball]) -> usize {
+    balls.iter().enumerate()
+        .map(|(index, ball)| {
+            balls[index + 1..].iter()
+                .filter(|other| other.position.distance(ball.position) < RADIUS * 2.0)
+                .count()
+        })
+        .sum()
+}
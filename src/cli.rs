@@ -0,0 +1,72 @@
+
+use std::{env, path::PathBuf};
+
+use crate::game::difficulty::Difficulty;
+
+// command line options, e.g. `ball_game --seed 1234`
+#[derive(Debug, Default)]
+pub struct CliArgs {
+    pub seed: Option<u64>,
+    pub replay: Option<PathBuf>,
+    pub difficulty: Option<Difficulty>,
+    // runs without a window as fast as possible, see `GamePlugin::headless`
+    pub headless: bool,
+    pub runs: Option<u32>,
+}
+
+impl CliArgs {
+    pub fn parse() -> Self {
+        CliArgs::parse_from(env::args().skip(1))
+    }
+
+    pub fn parse_from(args: impl IntoIterator<Item = String>) -> Self {
+        let mut cli_args = CliArgs::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+
+            match flag.as_str() {
+                "--seed" => {
+                    let value = inline_value.or_else(|| args.next());
+                    cli_args.seed = parse_value(&flag, value);
+                },
+                "--replay" => {
+                    let value = inline_value.or_else(|| args.next());
+                    cli_args.replay = parse_value(&flag, value);
+                },
+                "--difficulty" => {
+                    let value = inline_value.or_else(|| args.next());
+                    cli_args.difficulty = parse_value(&flag, value);
+                },
+                "--headless" => cli_args.headless = true,
+                "--runs" => {
+                    let value = inline_value.or_else(|| args.next());
+                    cli_args.runs = parse_value(&flag, value);
+                },
+                _ => eprintln!("Ignoring unknown argument: {}", flag),
+            }
+        }
+
+        cli_args
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Option<T> {
+    match value {
+        Some(value) => match value.parse() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                eprintln!("Ignoring invalid value for {}: {}", flag, value);
+                None
+            }
+        },
+        None => {
+            eprintln!("Missing value for {}", flag);
+            None
+        }
+    }
+}
\ No newline at end of file
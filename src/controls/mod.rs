@@ -0,0 +1,30 @@
+pub mod resources;
+mod systems;
+
+use bevy::{prelude::*, input::InputSystem};
+
+use systems::*;
+use resources::*;
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum ControlsSystemSet {
+    Actions,
+}
+
+// turns keys and gamepad buttons into `Input<InputAction>`,
+// which every system reads instead of the raw devices
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .init_resource::<InputBindings>()
+        .init_resource::<Input<InputAction>>()
+        .add_systems(Startup, load_input_bindings)
+        .add_systems(PreUpdate,
+            update_action_input
+                .in_set(ControlsSystemSet::Actions)
+                .after(InputSystem)
+        );
+    }
+}
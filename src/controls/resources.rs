@@ -0,0 +1,279 @@
+use std::collections::BTreeMap;
+
+use bevy::{prelude::*, log::warn};
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{self, StorageError};
+
+pub const CONTROLS_FILE_NAME: &str = "controls.ron";
+pub const CONTROLS_FILE_VERSION: u32 = 1;
+// every action can have two keys, e.g. an arrow and a letter
+pub const KEY_SLOTS: usize = 2;
+
+// what the player wants to do, independent of the key or button behind it
+#[derive(Serialize, Deserialize, Debug, Clone, Copy,
+        PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InputAction {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Pause,
+    Confirm,
+    Back,
+    StartGame,
+    QuitToMenu,
+    Quit,
+}
+
+impl InputAction {
+    pub const ALL: [InputAction; 10] = [
+        InputAction::MoveUp,
+        InputAction::MoveDown,
+        InputAction::MoveLeft,
+        InputAction::MoveRight,
+        InputAction::Pause,
+        InputAction::Confirm,
+        InputAction::Back,
+        InputAction::StartGame,
+        InputAction::QuitToMenu,
+        InputAction::Quit,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InputAction::MoveUp => "Move Up",
+            InputAction::MoveDown => "Move Down",
+            InputAction::MoveLeft => "Move Left",
+            InputAction::MoveRight => "Move Right",
+            InputAction::Pause => "Pause",
+            InputAction::Confirm => "Confirm",
+            InputAction::Back => "Back",
+            InputAction::StartGame => "Start Game",
+            InputAction::QuitToMenu => "Quit to Menu",
+            InputAction::Quit => "Quit",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Binding {
+    Key(KeyCode),
+    Gamepad(GamepadButtonType),
+}
+
+impl Binding {
+    pub fn label(&self) -> String {
+        match self {
+            Binding::Key(key_code) => match key_code {
+                KeyCode::Return => "Enter".to_string(),
+                KeyCode::Back => "Backspace".to_string(),
+                KeyCode::Escape => "Esc".to_string(),
+                key_code => format!("{:?}", key_code),
+            },
+            Binding::Gamepad(button_type) => match button_type {
+                GamepadButtonType::South => "A".to_string(),
+                GamepadButtonType::East => "B".to_string(),
+                GamepadButtonType::West => "X".to_string(),
+                GamepadButtonType::North => "Y".to_string(),
+                GamepadButtonType::LeftTrigger => "LB".to_string(),
+                GamepadButtonType::RightTrigger => "RB".to_string(),
+                GamepadButtonType::LeftTrigger2 => "LT".to_string(),
+                GamepadButtonType::RightTrigger2 => "RT".to_string(),
+                GamepadButtonType::DPadUp => "D-Pad Up".to_string(),
+                GamepadButtonType::DPadDown => "D-Pad Down".to_string(),
+                GamepadButtonType::DPadLeft => "D-Pad Left".to_string(),
+                GamepadButtonType::DPadRight => "D-Pad Right".to_string(),
+                button_type => format!("{:?}", button_type),
+            },
+        }
+    }
+}
+
+// where a binding lives in the controls menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingSlot {
+    Key(usize),
+    Gamepad,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct ActionBindings {
+    pub keys: [Option<KeyCode>; KEY_SLOTS],
+    pub gamepad: Option<GamepadButtonType>,
+}
+
+impl ActionBindings {
+    fn new(keys: [Option<KeyCode>; KEY_SLOTS], gamepad: Option<GamepadButtonType>) -> Self {
+        ActionBindings { keys, gamepad }
+    }
+
+    fn contains(&self, binding: Binding) -> bool {
+        match binding {
+            Binding::Key(key_code) => self.keys.contains(&Some(key_code)),
+            Binding::Gamepad(button_type) => self.gamepad == Some(button_type),
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct InputBindings {
+    bindings: BTreeMap<InputAction, ActionBindings>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        use GamepadButtonType as Pad;
+
+        let bindings = [
+            (InputAction::MoveUp, [Some(KeyCode::Up), Some(KeyCode::W)], Some(Pad::DPadUp)),
+            (InputAction::MoveDown, [Some(KeyCode::Down), Some(KeyCode::S)], Some(Pad::DPadDown)),
+            (InputAction::MoveLeft, [Some(KeyCode::Left), Some(KeyCode::A)], Some(Pad::DPadLeft)),
+            (InputAction::MoveRight, [Some(KeyCode::Right), Some(KeyCode::D)], Some(Pad::DPadRight)),
+            (InputAction::Pause, [Some(KeyCode::Space), None], Some(Pad::Start)),
+            (InputAction::Confirm, [Some(KeyCode::Return), None], Some(Pad::South)),
+            (InputAction::Back, [Some(KeyCode::Back), None], Some(Pad::East)),
+            (InputAction::StartGame, [Some(KeyCode::G), None], None),
+            (InputAction::QuitToMenu, [Some(KeyCode::M), None], Some(Pad::Select)),
+            (InputAction::Quit, [Some(KeyCode::Escape), None], None),
+        ];
+
+        InputBindings {
+            bindings: bindings.into_iter()
+                .map(|(action, keys, gamepad)| (action, ActionBindings::new(keys, gamepad)))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct ControlsFile {
+    version: u32,
+    bindings: BTreeMap<InputAction, ActionBindings>,
+}
+
+// read on its own first so a future format is reported, not misparsed
+#[derive(Deserialize)]
+struct FileVersion {
+    version: u32,
+}
+
+impl InputBindings {
+    pub fn action(&self, action: InputAction) -> &ActionBindings {
+        // every action is filled in by default and on load
+        &self.bindings[&action]
+    }
+
+    pub fn get(&self, action: InputAction, slot: BindingSlot) -> Option<Binding> {
+        let bindings = self.action(action);
+
+        match slot {
+            BindingSlot::Key(index) => bindings.keys[index].map(Binding::Key),
+            BindingSlot::Gamepad => bindings.gamepad.map(Binding::Gamepad),
+        }
+    }
+
+    // the binding has to fit the slot, a key can not go in the gamepad column
+    pub fn set(&mut self, action: InputAction, slot: BindingSlot, binding: Option<Binding>) {
+        let Some(bindings) = self.bindings.get_mut(&action) else {
+            return;
+        };
+
+        match (slot, binding) {
+            (BindingSlot::Key(index), Some(Binding::Key(key_code))) =>
+                bindings.keys[index] = Some(key_code),
+            (BindingSlot::Key(index), None) => bindings.keys[index] = None,
+            (BindingSlot::Gamepad, Some(Binding::Gamepad(button_type))) =>
+                bindings.gamepad = Some(button_type),
+            (BindingSlot::Gamepad, None) => bindings.gamepad = None,
+            _ => {},
+        }
+    }
+
+    // the other action already using the binding, if any
+    pub fn conflict(&self, action: InputAction, binding: Binding) -> Option<InputAction> {
+        self.bindings.iter()
+            .find(|(other, bindings)| **other != action && bindings.contains(binding))
+            .map(|(other, _)| *other)
+    }
+
+    pub fn is_pressed(
+        &self,
+        action: InputAction,
+        keyboard_input: &Input<KeyCode>,
+        gamepads: &Gamepads,
+        gamepad_buttons: &Input<GamepadButton>
+    ) -> bool {
+        let bindings = self.action(action);
+        let key_pressed = bindings.keys.iter()
+            .flatten()
+            .any(|key_code| keyboard_input.pressed(*key_code));
+        let button_pressed = bindings.gamepad.is_some_and(|button_type| {
+            gamepads.iter().any(|gamepad| gamepad_buttons
+                .pressed(GamepadButton::new(gamepad, button_type)))
+        });
+
+        key_pressed || button_pressed
+    }
+
+    pub fn load() -> Self {
+        let Some(path) = storage::data_file(CONTROLS_FILE_NAME) else {
+            return InputBindings::default();
+        };
+
+        match read_controls_file(&path) {
+            Ok(bindings) => bindings,
+            Err(StorageError::NotFound) => InputBindings::default(),
+            Err(error) => {
+                warn!("Could not read controls from {}: {}",
+                        path.display(), error);
+                if let Some(backup_path) = storage::back_up_file(&path) {
+                    warn!("Moved unreadable controls to {}",
+                            backup_path.display());
+                }
+
+                InputBindings::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = storage::data_file(CONTROLS_FILE_NAME) else {
+            return;
+        };
+
+        let file = ControlsFile {
+            version: CONTROLS_FILE_VERSION,
+            bindings: self.bindings.clone(),
+        };
+
+        if let Err(error) = storage::save_ron(&path, &file) {
+            warn!("Could not save controls to {}: {}",
+                    path.display(), error);
+        }
+    }
+}
+
+fn read_controls_file(
+    path: &std::path::Path
+) -> Result<InputBindings, StorageError> {
+    let file_version: FileVersion = storage::load_ron(path)?;
+    if file_version.version != CONTROLS_FILE_VERSION {
+        return Err(StorageError::UnsupportedVersion(file_version.version));
+    }
+
+    let file: ControlsFile = storage::load_ron(path)?;
+    // actions added since the file was written keep their defaults
+    let mut bindings = InputBindings::default();
+    bindings.bindings.extend(file.bindings);
+
+    Ok(bindings)
+}
+
+// set while the controls menu waits for the new key or button
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingRebind {
+    pub action: InputAction,
+    pub slot: BindingSlot,
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use crate::controls::resources::*;
+
+pub fn load_input_bindings(mut input_bindings: ResMut<InputBindings>) {
+    *input_bindings = InputBindings::load();
+}
+
+pub fn update_action_input(
+    input_bindings: Res<InputBindings>,
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mut action_input: ResMut<Input<InputAction>>
+) {
+    action_input.clear();
+
+    InputAction::ALL.iter().for_each(|action| {
+        let pressed = input_bindings.is_pressed(*action, &keyboard_input,
+                                            &gamepads, &gamepad_buttons);
+
+        if pressed && !action_input.pressed(*action) {
+            action_input.press(*action);
+        } else if !pressed && action_input.pressed(*action) {
+            action_input.release(*action);
+        }
+    });
+}
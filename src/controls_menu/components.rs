@@ -0,0 +1,28 @@
+use bevy::prelude::Component;
+
+use crate::controls::resources::{BindingSlot, InputAction};
+
+#[derive(Component)]
+pub struct ControlsMenu {}
+
+#[derive(Component)]
+pub struct BindingButton {
+    pub action: InputAction,
+    pub slot: BindingSlot,
+}
+
+// the text inside a binding button
+#[derive(Component)]
+pub struct BindingLabel {
+    pub action: InputAction,
+    pub slot: BindingSlot,
+}
+
+#[derive(Component)]
+pub struct StatusText {}
+
+#[derive(Component)]
+pub struct ResetButton {}
+
+#[derive(Component)]
+pub struct BackButton {}
@@ -0,0 +1,34 @@
+pub mod components;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::controls::resources::PendingRebind;
+use systems::layout::*;
+use systems::interactions::*;
+
+pub struct ControlsMenuPlugin;
+
+impl Plugin for ControlsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_systems(OnEnter(AppState::Controls),
+                    spawn_controls_menu)
+        .add_systems(Update, (
+                (
+                    interact_with_binding_buttons,
+                    capture_rebind.run_if(resource_exists::<PendingRebind>()),
+                    update_binding_labels
+                ).chain(),
+                interact_with_reset_button,
+                interact_with_back_button
+            )
+            .run_if(in_state(AppState::Controls))
+        )
+        .add_systems(OnExit(AppState::Controls), (
+                    despawn_controls_menu,
+                    cancel_rebind
+        ));
+    }
+}
@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+
+use crate::{
+    main_menu::styles::*,
+    controls_menu::components::*,
+    controls::resources::*,
+    AppState
+};
+use super::layout::{binding_label, DEFAULT_STATUS};
+
+pub fn interact_with_binding_buttons(
+    mut commands: Commands,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor, &BindingButton),
+                        Changed<Interaction>>,
+    mut status_query: Query<&mut Text, With<StatusText>>
+) {
+    button_query.for_each_mut(|(interaction, mut background_color, binding_button)| {
+        *background_color = match *interaction {
+            Interaction::Pressed => {
+                commands.insert_resource(PendingRebind {
+                    action: binding_button.action,
+                    slot: binding_button.slot,
+                });
+                set_status(&mut status_query, rebind_prompt(binding_button));
+                PRESSED_BUTTON_COLOR.into()
+            },
+            Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => NORMAL_BUTTON_COLOR.into(),
+        }
+    });
+}
+
+fn rebind_prompt(binding_button: &BindingButton) -> String {
+    let device = match binding_button.slot {
+        BindingSlot::Key(_) => "a key",
+        BindingSlot::Gamepad => "a gamepad button",
+    };
+
+    format!("Press {} for {} (Esc cancels, Delete clears)",
+            device, binding_button.action.label())
+}
+
+fn set_status(status_query: &mut Query<&mut Text, With<StatusText>>, status: String) {
+    if let Ok(mut text) = status_query.get_single_mut() {
+        text.sections[0].value = status;
+    }
+}
+
+pub fn capture_rebind(
+    mut commands: Commands,
+    pending_rebind: Res<PendingRebind>,
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mut input_bindings: ResMut<InputBindings>,
+    mut status_query: Query<&mut Text, With<StatusText>>
+) {
+    // the key or button that opened the prompt is not the new binding
+    if pending_rebind.is_added() {
+        return;
+    }
+
+    let PendingRebind { action, slot } = *pending_rebind;
+
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        commands.remove_resource::<PendingRebind>();
+        set_status(&mut status_query, DEFAULT_STATUS.to_string());
+        return;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Delete) {
+        input_bindings.set(action, slot, None);
+        input_bindings.save();
+        commands.remove_resource::<PendingRebind>();
+        set_status(&mut status_query,
+                format!("Cleared a binding of {}", action.label()));
+        return;
+    }
+
+    let binding = match slot {
+        BindingSlot::Key(_) => keyboard_input.get_just_pressed()
+            .next()
+            .map(|key_code| Binding::Key(*key_code)),
+        BindingSlot::Gamepad => gamepad_buttons.get_just_pressed()
+            .next()
+            .map(|button| Binding::Gamepad(button.button_type)),
+    };
+    let Some(binding) = binding else {
+        return;
+    };
+
+    // keeps waiting so the player can pick another one
+    if let Some(other) = input_bindings.conflict(action, binding) {
+        set_status(&mut status_query,
+                format!("{} is already bound to {}, try another",
+                        binding.label(), other.label()));
+        return;
+    }
+
+    input_bindings.set(action, slot, Some(binding));
+    input_bindings.save();
+    commands.remove_resource::<PendingRebind>();
+    set_status(&mut status_query,
+            format!("{} bound to {}", action.label(), binding.label()));
+}
+
+pub fn update_binding_labels(
+    input_bindings: Res<InputBindings>,
+    pending_rebind: Option<Res<PendingRebind>>,
+    mut label_query: Query<(&mut Text, &BindingLabel)>
+) {
+    let pending = pending_rebind.map(|pending_rebind| *pending_rebind);
+
+    label_query.for_each_mut(|(mut text, label)| {
+        let value = if pending == Some(PendingRebind { action: label.action, slot: label.slot }) {
+            "...".to_string()
+        } else {
+            binding_label(input_bindings.get(label.action, label.slot))
+        };
+
+        // only touched when it differs so the text is not laid out every frame
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+    });
+}
+
+pub fn interact_with_reset_button(
+    mut commands: Commands,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor),
+                        (Changed<Interaction>, With<ResetButton>)>,
+    mut input_bindings: ResMut<InputBindings>,
+    mut status_query: Query<&mut Text, With<StatusText>>
+) {
+    if let Ok((interaction, mut background_color)) =
+                button_query.get_single_mut() {
+                    *background_color = match *interaction {
+                    Interaction::Pressed => {
+                        *input_bindings = InputBindings::default();
+                        input_bindings.save();
+                        commands.remove_resource::<PendingRebind>();
+                        set_status(&mut status_query,
+                                "Controls reset to defaults".to_string());
+                        PRESSED_BUTTON_COLOR.into()
+                    },
+                    Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+                    Interaction::None => NORMAL_BUTTON_COLOR.into(),
+                }
+    }
+}
+
+pub fn interact_with_back_button(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor),
+                        (Changed<Interaction>, With<BackButton>)>,
+    mut next_app_state: ResMut<NextState<AppState>>
+) {
+    if let Ok((interaction, mut background_color)) =
+                button_query.get_single_mut() {
+                    *background_color = match *interaction {
+                    Interaction::Pressed => {
+                        next_app_state.set(AppState::MainMenu);
+                        PRESSED_BUTTON_COLOR.into()
+                    },
+                    Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+                    Interaction::None => NORMAL_BUTTON_COLOR.into(),
+                }
+    }
+}
+
+pub fn cancel_rebind(mut commands: Commands) {
+    commands.remove_resource::<PendingRebind>();
+}
@@ -0,0 +1,256 @@
+use bevy::prelude::*;
+
+use crate::{
+    controls_menu::components::*,
+    controls::resources::*,
+    main_menu::styles::*,
+};
+use crate::loading::resources::GameAssets;
+
+const COLUMN_WIDTHS: [f32; 4] = [220.0, 180.0, 180.0, 180.0];
+pub const DEFAULT_STATUS: &str = "Click a binding to change it";
+
+// smaller than the other menus' buttons so every action fits on screen
+const fn binding_button_style() -> Style {
+    let mut style = Style::DEFAULT;
+    style.width = Val::Px(172.0);
+    style.height = Val::Px(36.0);
+    style.justify_content = JustifyContent::Center;
+    style.align_items = AlignItems::Center;
+
+    style
+}
+
+const BINDING_BUTTON_STYLE: Style = binding_button_style();
+
+pub fn spawn_controls_menu(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    input_bindings: Res<InputBindings>
+) {
+    build_controls_menu(&mut commands, &game_assets, &input_bindings);
+}
+
+pub fn despawn_controls_menu(
+    mut commands: Commands,
+    controls_menu_query: Query<Entity, With<ControlsMenu>>
+) {
+    if let Ok(controls_menu_entity) = controls_menu_query.get_single() {
+        commands.entity(controls_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn binding_label(binding: Option<Binding>) -> String {
+    binding.map(|binding| binding.label())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+pub fn build_controls_menu(
+    commands: &mut Commands,
+    game_assets: &Res<GameAssets>,
+    input_bindings: &InputBindings
+) -> Entity {
+    let controls_menu_entity = commands.spawn(
+        (NodeBundle {
+            style: MAIN_MENU_STYLE,
+            ..default()
+        },
+        ControlsMenu {},
+    ))
+    .with_children(|parent| {
+        // === Title ===
+        parent.spawn(
+            NodeBundle {
+                style: TITLE_STYLE,
+                ..default()
+            }
+        ).with_children(|parent| {
+            parent.spawn(
+                TextBundle {
+                    text: Text {
+                        sections: vec![
+                            TextSection::new(
+                                "Controls",
+                                get_title_text_style(game_assets)
+                            )
+                        ],
+                        alignment: TextAlignment::Center,
+                        ..default()
+                    },
+                    ..default()
+                }
+            );
+        });
+        // === Table ===
+        parent.spawn(
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                ..default()
+            }
+        ).with_children(|parent| {
+            spawn_header(parent, game_assets);
+
+            InputAction::ALL.iter().for_each(|action| {
+                spawn_action_row(parent, game_assets, input_bindings, *action);
+            });
+        });
+        // === Status ===
+        parent.spawn((
+            TextBundle::from_section(
+                DEFAULT_STATUS,
+                get_small_button_text_style(game_assets)
+            ),
+            StatusText {}
+        ));
+        // === Buttons ===
+        parent.spawn(
+            NodeBundle {
+                style: OPTIONS_ROW_STYLE,
+                ..default()
+            }
+        ).with_children(|parent| {
+            spawn_text_button(parent, game_assets, "Reset", ResetButton {});
+            spawn_text_button(parent, game_assets, "Back", BackButton {});
+        });
+    })
+    .id();
+
+    controls_menu_entity
+}
+
+fn spawn_header(parent: &mut ChildBuilder, game_assets: &Res<GameAssets>) {
+    parent.spawn(
+        NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                ..default()
+            },
+            ..default()
+        }
+    ).with_children(|parent| {
+        ["Action", "Key", "Alt Key", "Gamepad"].into_iter()
+            .zip(COLUMN_WIDTHS)
+            .for_each(|(title, width)| {
+                parent.spawn(
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(width),
+                            ..default()
+                        },
+                        ..default()
+                    }
+                ).with_children(|parent| {
+                    parent.spawn(
+                        TextBundle::from_section(
+                            title,
+                            get_small_button_text_style(game_assets)
+                        )
+                    );
+                });
+            });
+    });
+}
+
+fn spawn_action_row(
+    parent: &mut ChildBuilder,
+    game_assets: &Res<GameAssets>,
+    input_bindings: &InputBindings,
+    action: InputAction
+) {
+    let slots = [BindingSlot::Key(0), BindingSlot::Key(1), BindingSlot::Gamepad];
+
+    parent.spawn(
+        NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            ..default()
+        }
+    ).with_children(|parent| {
+        parent.spawn(
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(COLUMN_WIDTHS[0]),
+                    ..default()
+                },
+                ..default()
+            }
+        ).with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(
+                    action.label(),
+                    get_small_button_text_style(game_assets)
+                )
+            );
+        });
+
+        slots.into_iter()
+            .zip(&COLUMN_WIDTHS[1..])
+            .for_each(|(slot, width)| {
+                parent.spawn(
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(*width),
+                            ..default()
+                        },
+                        ..default()
+                    }
+                ).with_children(|parent| {
+                    parent.spawn((
+                        ButtonBundle {
+                            style: BINDING_BUTTON_STYLE,
+                            background_color: NORMAL_BUTTON_COLOR.into(),
+                            ..default()
+                        },
+                        BindingButton { action, slot }
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            TextBundle::from_section(
+                                binding_label(input_bindings.get(action, slot)),
+                                get_small_button_text_style(game_assets)
+                            ),
+                            BindingLabel { action, slot }
+                        ));
+                    });
+                });
+            });
+    });
+}
+
+fn spawn_text_button(
+    parent: &mut ChildBuilder,
+    game_assets: &Res<GameAssets>,
+    text: &str,
+    marker: impl Component
+) {
+    parent.spawn((
+        ButtonBundle {
+            style: SMALL_BUTTON_STYLE,
+            background_color: NORMAL_BUTTON_COLOR.into(),
+            ..default()
+        },
+        marker
+    ))
+    .with_children(|parent| {
+        parent.spawn(
+        TextBundle {
+            text: Text {
+                sections: vec![
+                    TextSection::new(
+                        text,
+                        get_small_button_text_style(game_assets)
+                )],
+                alignment: TextAlignment::Center,
+                ..default()
+            },
+            ..default()
+        });
+    });
+}
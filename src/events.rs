@@ -1,7 +1,103 @@
 
-use bevy::prelude::Event;
+use bevy::prelude::{Entity, Event, Vec2};
+
+use crate::game::power_up::resources::PowerUpKind;
 
 #[derive(Event)]
 pub struct GameOver {
     pub score: u32,
+    // the longest run of stars collected in quick succession
+    pub best_combo: u32,
+}
+
+// a request for a sound, gameplay events are turned into these by the sound
+// plugin so another one can take its place
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlaySound {
+    pub sound: SoundEffect,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEffect {
+    EnemyBounce,
+    StarCollected,
+    PlayerHit,
+    PowerUpCollected,
+}
+
+// the star is despawned by the time this is read, only its id is left
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct StarCollected {
+    pub star: Entity,
+    pub position: Vec2,
+}
+
+// an enemy turned around, off a wall or off another enemy
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct EnemyBounced {
+    pub enemy: Entity,
+    // the enemy it bounced off, None for a wall
+    pub other: Option<Entity>,
+    // where the two met, or the enemy's own position for a wall
+    pub position: Vec2,
+}
+
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct EnemySpawned {
+    pub enemy: Entity,
+    pub archetype: usize,
+    pub position: Vec2,
+}
+
+// sent for every hit that lands, before any life is taken
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct PlayerHit {
+    pub player: Entity,
+    pub enemy: Entity,
+    // where the player was hit, it may be moved somewhere safe right after
+    pub position: Vec2,
+    // a shield charge took the hit instead of a life
+    pub absorbed: bool,
+}
+
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct PowerUpCollected {
+    pub power_up: Entity,
+    pub kind: PowerUpKind,
+    pub position: Vec2,
+}
+
+// two colliders started touching, sent once until they come apart again
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionStarted {
+    pub entities: [Entity; 2],
+}
+
+// two colliders that were touching no longer are, or one of them is gone
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionEnded {
+    pub entities: [Entity; 2],
+}
+
+impl CollisionStarted {
+    // the entity the given one collided with, if it is part of this pair
+    pub fn other(&self, entity: Entity) -> Option<Entity> {
+        other_in_pair(self.entities, entity)
+    }
+}
+
+impl CollisionEnded {
+    pub fn other(&self, entity: Entity) -> Option<Entity> {
+        other_in_pair(self.entities, entity)
+    }
+}
+
+pub fn other_in_pair([a, b]: [Entity; 2], entity: Entity) -> Option<Entity> {
+    if a == entity {
+        Some(b)
+    } else if b == entity {
+        Some(a)
+    } else {
+        None
+    }
 }
@@ -0,0 +1,611 @@
+
+use std::{fmt, path::PathBuf};
+
+use bevy::{prelude::Resource, log::{error, info}};
+use serde::Deserialize;
+
+use crate::storage::{self, StorageError};
+use crate::loading::resources::ENEMY_SPRITE_PATH;
+use crate::game::difficulty::Difficulty;
+
+pub const GAME_CONFIG_PATH: &str = "config/game.ron";
+
+// values a run can not sensibly go beyond
+const MAX_SPAWN_COUNT: usize = 1000;
+const MAX_SPEED: f32 = 10_000.0;
+// room for the player and an enemy side by side
+const MIN_ARENA_SIZE: f32 = 128.0;
+const MAX_LIVES: u32 = 99;
+const MAX_SHIELD_CHARGES: u32 = 9;
+const MAX_COMBO_MULTIPLIER: u32 = 99;
+// smaller is hard to see, larger does not fit in the smallest arena
+const MIN_ENEMY_SIZE: f32 = 8.0;
+const MAX_ENEMY_SIZE: f32 = MIN_ARENA_SIZE;
+
+#[derive(Resource, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct GameConfig {
+    pub player: PlayerConfig,
+    pub enemy: EnemyConfig,
+    pub star: StarConfig,
+    pub power_up: PowerUpConfig,
+    pub combo: ComboConfig,
+    pub difficulty: DifficultyPresets,
+    pub arena: ArenaConfig,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PlayerConfig {
+    pub speed: f32,
+    pub lives: u32,
+    // how long the player blinks and can not be hit after losing a life
+    pub invulnerable_time: f32,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct EnemyConfig {
+    pub count: usize,
+    pub spawn_time: f32,
+    pub archetypes: Vec<EnemyArchetype>,
+}
+
+// one kind of enemy, each spawn picks one at random by weight
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct EnemyArchetype {
+    pub name: String,
+    // chance of being picked relative to the others,
+    // zero for ones that only appear out of a splitter
+    pub weight: f32,
+    pub sprite: String,
+    // multiplied with the sprite's own colors
+    pub color: (f32, f32, f32),
+    // drawn size and distance kept from the walls
+    pub size: f32,
+    pub radius: f32,
+    // how hard it pushes others around when enemies collide
+    pub mass: f32,
+    pub speed: f32,
+    pub behaviour: EnemyBehaviour,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub enum EnemyBehaviour {
+    // straight lines, off the walls
+    Bouncer,
+    // turns toward the player, at most turn_rate radians per second
+    Homing { turn_rate: f32 },
+    // gains acceleration pixels per second every second up to max_speed
+    Accelerating { acceleration: f32, max_speed: f32 },
+    // breaks into two of another archetype on its nth bounce
+    Splitter { bounces: u32, into: String },
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct StarConfig {
+    pub count: usize,
+    pub spawn_time: f32,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PowerUpConfig {
+    pub spawn_time: f32,
+    // most pickups waiting in the arena at once
+    pub max_count: usize,
+    pub shield: ShieldConfig,
+    pub slow_motion: SlowMotionConfig,
+    pub magnet: MagnetConfig,
+    pub bomb: BombConfig,
+}
+
+// every pickup adds a charge and restarts the timer,
+// each charge absorbs one enemy hit
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShieldConfig {
+    pub duration: f32,
+    pub max_charges: u32,
+}
+
+// another pickup restarts the timer, the slowdown does not compound
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct SlowMotionConfig {
+    pub duration: f32,
+    pub enemy_speed: f32,
+}
+
+// another pickup restarts the timer
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MagnetConfig {
+    pub duration: f32,
+    pub radius: f32,
+    pub speed: f32,
+}
+
+// goes off as soon as it is picked up
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct BombConfig {
+    pub count: usize,
+}
+
+// every star restarts the window, the combo is lost when it runs out.
+// the multiplier goes up by one every stars_per_step stars in the combo
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ComboConfig {
+    pub window: f32,
+    pub stars_per_step: u32,
+    pub max_multiplier: u32,
+}
+
+// multipliers applied on top of the base values above
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DifficultyPreset {
+    pub enemy_count: f32,
+    pub enemy_speed: f32,
+    pub enemy_spawn_time: f32,
+    pub star_spawn_time: f32,
+    // enemies bounce off each other instead of passing through
+    pub enemy_collisions: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DifficultyPresets {
+    pub easy: DifficultyPreset,
+    pub normal: DifficultyPreset,
+    pub hard: DifficultyPreset,
+    pub insane: DifficultyPreset,
+}
+
+// the play area in logical pixels, scaled to fit the window
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ArenaConfig {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Default for PlayerConfig {
+    fn default() -> Self {
+        PlayerConfig {
+            speed: 500.0,
+            lives: 3,
+            invulnerable_time: 2.0,
+        }
+    }
+}
+
+impl Default for EnemyConfig {
+    fn default() -> Self {
+        EnemyConfig {
+            count: 4,
+            spawn_time: 5.0,
+            archetypes: vec![EnemyArchetype::default()],
+        }
+    }
+}
+
+// the plain red ball every enemy used to be
+impl Default for EnemyArchetype {
+    fn default() -> Self {
+        EnemyArchetype {
+            name: "bouncer".to_string(),
+            weight: 1.0,
+            sprite: ENEMY_SPRITE_PATH.to_string(),
+            color: (1.0, 1.0, 1.0),
+            size: 64.0,
+            radius: 32.0,
+            mass: 1.0,
+            speed: 200.0,
+            behaviour: EnemyBehaviour::Bouncer,
+        }
+    }
+}
+
+impl Default for StarConfig {
+    fn default() -> Self {
+        StarConfig {
+            count: 10,
+            spawn_time: 1.0,
+        }
+    }
+}
+
+impl Default for PowerUpConfig {
+    fn default() -> Self {
+        PowerUpConfig {
+            spawn_time: 10.0,
+            max_count: 2,
+            shield: ShieldConfig::default(),
+            slow_motion: SlowMotionConfig::default(),
+            magnet: MagnetConfig::default(),
+            bomb: BombConfig::default(),
+        }
+    }
+}
+
+impl Default for ShieldConfig {
+    fn default() -> Self {
+        ShieldConfig {
+            duration: 10.0,
+            max_charges: 3,
+        }
+    }
+}
+
+impl Default for SlowMotionConfig {
+    fn default() -> Self {
+        SlowMotionConfig {
+            duration: 5.0,
+            enemy_speed: 0.5,
+        }
+    }
+}
+
+impl Default for MagnetConfig {
+    fn default() -> Self {
+        MagnetConfig {
+            duration: 8.0,
+            radius: 250.0,
+            speed: 400.0,
+        }
+    }
+}
+
+impl Default for BombConfig {
+    fn default() -> Self {
+        BombConfig {
+            count: 3,
+        }
+    }
+}
+
+impl Default for ComboConfig {
+    fn default() -> Self {
+        ComboConfig {
+            window: 2.0,
+            stars_per_step: 5,
+            max_multiplier: 5,
+        }
+    }
+}
+
+impl Default for DifficultyPreset {
+    fn default() -> Self {
+        DifficultyPreset {
+            enemy_count: 1.0,
+            enemy_speed: 1.0,
+            enemy_spawn_time: 1.0,
+            star_spawn_time: 1.0,
+            enemy_collisions: false,
+        }
+    }
+}
+
+impl Default for DifficultyPresets {
+    fn default() -> Self {
+        DifficultyPresets {
+            easy: DifficultyPreset {
+                enemy_count: 0.5,
+                enemy_speed: 0.75,
+                enemy_spawn_time: 1.5,
+                star_spawn_time: 0.75,
+                enemy_collisions: false,
+            },
+            normal: DifficultyPreset::default(),
+            hard: DifficultyPreset {
+                enemy_count: 1.5,
+                enemy_speed: 1.25,
+                enemy_spawn_time: 0.75,
+                star_spawn_time: 1.25,
+                enemy_collisions: true,
+            },
+            insane: DifficultyPreset {
+                enemy_count: 2.0,
+                enemy_speed: 1.6,
+                enemy_spawn_time: 0.5,
+                star_spawn_time: 1.5,
+                enemy_collisions: true,
+            },
+        }
+    }
+}
+
+impl Default for ArenaConfig {
+    fn default() -> Self {
+        ArenaConfig {
+            width: 1280.0,
+            height: 720.0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum GameConfigError {
+    Read(StorageError),
+    Invalid(Vec<String>),
+}
+
+impl fmt::Display for GameConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameConfigError::Read(error) => write!(f, "{}", error),
+            GameConfigError::Invalid(problems) =>
+                write!(f, "invalid values:\n  {}", problems.join("\n  ")),
+        }
+    }
+}
+
+impl GameConfig {
+    // falls back to the built in values so a bad edit never stops the game
+    pub fn load() -> Self {
+        let path = game_config_path();
+
+        match GameConfig::load_from(&path) {
+            Ok(config) => {
+                info!("Loaded game config from {}", path.display());
+                config
+            },
+            Err(error) => {
+                error!("Could not use game config {}: {}\nUsing default values",
+                        path.display(), error);
+                GameConfig::default()
+            }
+        }
+    }
+
+    pub fn load_from(path: &std::path::Path) -> Result<Self, GameConfigError> {
+        let config: GameConfig = storage::load_ron(path)
+            .map_err(GameConfigError::Read)?;
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), GameConfigError> {
+        let mut problems = Vec::new();
+
+        check_speed(&mut problems, "player.speed", self.player.speed);
+        check_lives(&mut problems, "player.lives", self.player.lives);
+        check_duration(&mut problems, "player.invulnerable_time",
+                    self.player.invulnerable_time);
+        check_count(&mut problems, "enemy.count", self.enemy.count);
+        check_spawn_time(&mut problems, "enemy.spawn_time", self.enemy.spawn_time);
+        check_archetypes(&mut problems, &self.enemy.archetypes);
+        check_count(&mut problems, "star.count", self.star.count);
+        check_spawn_time(&mut problems, "star.spawn_time", self.star.spawn_time);
+        check_power_ups(&mut problems, &self.power_up);
+        check_combo(&mut problems, &self.combo);
+        check_arena_size(&mut problems, "arena.width", self.arena.width);
+        check_arena_size(&mut problems, "arena.height", self.arena.height);
+        Difficulty::ALL.iter().for_each(|difficulty| {
+            check_preset(&mut problems, difficulty, self.preset(*difficulty));
+        });
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(GameConfigError::Invalid(problems))
+        }
+    }
+
+    pub fn preset(&self, difficulty: Difficulty) -> &DifficultyPreset {
+        match difficulty {
+            Difficulty::Easy => &self.difficulty.easy,
+            Difficulty::Normal => &self.difficulty.normal,
+            Difficulty::Hard => &self.difficulty.hard,
+            Difficulty::Insane => &self.difficulty.insane,
+        }
+    }
+
+    pub fn enemy_count(&self, difficulty: Difficulty) -> usize {
+        let count = self.enemy.count as f32 * self.preset(difficulty).enemy_count;
+
+        count.round() as usize
+    }
+
+    pub fn enemy_speed(&self, difficulty: Difficulty, speed: f32) -> f32 {
+        speed * self.preset(difficulty).enemy_speed
+    }
+
+    pub fn enemy_collisions(&self, difficulty: Difficulty) -> bool {
+        self.preset(difficulty).enemy_collisions
+    }
+
+    pub fn archetype_index(&self, name: &str) -> Option<usize> {
+        self.enemy.archetypes.iter()
+            .position(|archetype| archetype.name == name)
+    }
+
+    pub fn enemy_spawn_time(&self, difficulty: Difficulty) -> f32 {
+        self.enemy.spawn_time * self.preset(difficulty).enemy_spawn_time
+    }
+
+    pub fn star_spawn_time(&self, difficulty: Difficulty) -> f32 {
+        self.star.spawn_time * self.preset(difficulty).star_spawn_time
+    }
+}
+
+fn check_speed(problems: &mut Vec<String>, name: &str, value: f32) {
+    if !(value.is_finite() && value > 0.0 && value <= MAX_SPEED) {
+        problems.push(format!("{} must be between 0 and {}, got {}",
+                            name, MAX_SPEED, value));
+    }
+}
+
+fn check_spawn_time(problems: &mut Vec<String>, name: &str, value: f32) {
+    if !(value.is_finite() && value > 0.0) {
+        problems.push(format!("{} must be a positive number of seconds, got {}",
+                            name, value));
+    }
+}
+
+fn check_lives(problems: &mut Vec<String>, name: &str, value: u32) {
+    if !(1..=MAX_LIVES).contains(&value) {
+        problems.push(format!("{} must be between 1 and {}, got {}",
+                            name, MAX_LIVES, value));
+    }
+}
+
+fn check_duration(problems: &mut Vec<String>, name: &str, value: f32) {
+    if !(value.is_finite() && value >= 0.0) {
+        problems.push(format!("{} must be zero or more seconds, got {}",
+                            name, value));
+    }
+}
+
+fn check_archetypes(problems: &mut Vec<String>, archetypes: &[EnemyArchetype]) {
+    if !archetypes.iter().any(|archetype| archetype.weight > 0.0) {
+        problems.push("enemy.archetypes needs at least one with a weight above 0".to_string());
+    }
+
+    archetypes.iter().enumerate().for_each(|(index, archetype)| {
+        let name = format!("enemy.archetypes.{}", archetype.name);
+
+        if archetypes[..index].iter().any(|other| other.name == archetype.name) {
+            problems.push(format!("{} is defined more than once", name));
+        }
+        if !(archetype.weight.is_finite() && archetype.weight >= 0.0) {
+            problems.push(format!("{}.weight must be zero or more, got {}",
+                                name, archetype.weight));
+        }
+        [("size", archetype.size), ("radius", archetype.radius * 2.0)]
+            .iter()
+            .for_each(|(field, value)| {
+                if !(value.is_finite() && (MIN_ENEMY_SIZE..=MAX_ENEMY_SIZE).contains(value)) {
+                    problems.push(format!("{}.{} must fit between {} and {} pixels across, got {}",
+                                        name, field, MIN_ENEMY_SIZE, MAX_ENEMY_SIZE, value));
+                }
+            });
+        if !(archetype.mass.is_finite() && archetype.mass > 0.0) {
+            problems.push(format!("{}.mass must be above 0, got {}",
+                                name, archetype.mass));
+        }
+        check_speed(problems, &format!("{}.speed", name), archetype.speed);
+
+        match &archetype.behaviour {
+            EnemyBehaviour::Bouncer => {},
+            EnemyBehaviour::Homing { turn_rate } => {
+                if !(turn_rate.is_finite() && *turn_rate >= 0.0) {
+                    problems.push(format!("{}.turn_rate must be zero or more radians per second, got {}",
+                                        name, turn_rate));
+                }
+            },
+            EnemyBehaviour::Accelerating { acceleration, max_speed } => {
+                if !(acceleration.is_finite() && *acceleration >= 0.0) {
+                    problems.push(format!("{}.acceleration must be zero or more, got {}",
+                                        name, acceleration));
+                }
+                check_speed(problems, &format!("{}.max_speed", name), *max_speed);
+            },
+            EnemyBehaviour::Splitter { bounces, into } => {
+                if *bounces == 0 {
+                    problems.push(format!("{}.bounces must be at least 1", name));
+                }
+                // a splitter breaking into splitters would never stop
+                match archetypes.iter().find(|other| other.name == *into) {
+                    None => problems.push(format!("{} splits into {}, which is not defined",
+                                                name, into)),
+                    Some(other) if matches!(other.behaviour, EnemyBehaviour::Splitter { .. }) =>
+                        problems.push(format!("{} splits into {}, which is a splitter too",
+                                            name, into)),
+                    Some(_) => {},
+                }
+            },
+        }
+    });
+}
+
+fn check_power_ups(problems: &mut Vec<String>, power_up: &PowerUpConfig) {
+    check_spawn_time(problems, "power_up.spawn_time", power_up.spawn_time);
+    check_count(problems, "power_up.max_count", power_up.max_count);
+    check_duration(problems, "power_up.shield.duration", power_up.shield.duration);
+    if !(1..=MAX_SHIELD_CHARGES).contains(&power_up.shield.max_charges) {
+        problems.push(format!("power_up.shield.max_charges must be between 1 and {}, got {}",
+                            MAX_SHIELD_CHARGES, power_up.shield.max_charges));
+    }
+    check_duration(problems, "power_up.slow_motion.duration",
+                power_up.slow_motion.duration);
+    let enemy_speed = power_up.slow_motion.enemy_speed;
+    if !(enemy_speed.is_finite() && enemy_speed > 0.0) {
+        problems.push(format!("power_up.slow_motion.enemy_speed must be a positive multiplier, got {}",
+                            enemy_speed));
+    }
+    check_duration(problems, "power_up.magnet.duration", power_up.magnet.duration);
+    let radius = power_up.magnet.radius;
+    if !(radius.is_finite() && radius >= 0.0) {
+        problems.push(format!("power_up.magnet.radius must be zero or more pixels, got {}",
+                            radius));
+    }
+    check_speed(problems, "power_up.magnet.speed", power_up.magnet.speed);
+    check_count(problems, "power_up.bomb.count", power_up.bomb.count);
+}
+
+fn check_combo(problems: &mut Vec<String>, combo: &ComboConfig) {
+    check_spawn_time(problems, "combo.window", combo.window);
+    if combo.stars_per_step == 0 {
+        problems.push("combo.stars_per_step must be at least 1".to_string());
+    }
+    if !(1..=MAX_COMBO_MULTIPLIER).contains(&combo.max_multiplier) {
+        problems.push(format!("combo.max_multiplier must be between 1 and {}, got {}",
+                            MAX_COMBO_MULTIPLIER, combo.max_multiplier));
+    }
+}
+
+fn check_arena_size(problems: &mut Vec<String>, name: &str, value: f32) {
+    if !(value.is_finite() && value >= MIN_ARENA_SIZE) {
+        problems.push(format!("{} must be at least {} pixels, got {}",
+                            name, MIN_ARENA_SIZE, value));
+    }
+}
+
+fn check_preset(
+    problems: &mut Vec<String>,
+    difficulty: &Difficulty,
+    preset: &DifficultyPreset
+) {
+    let multipliers = [
+        ("enemy_count", preset.enemy_count),
+        ("enemy_speed", preset.enemy_speed),
+        ("enemy_spawn_time", preset.enemy_spawn_time),
+        ("star_spawn_time", preset.star_spawn_time),
+    ];
+
+    multipliers.iter().for_each(|(name, value)| {
+        if !(value.is_finite() && *value > 0.0) {
+            problems.push(format!("difficulty.{}.{} must be a positive multiplier, got {}",
+                                difficulty.label().to_lowercase(), name, value));
+        }
+    });
+}
+
+fn check_count(problems: &mut Vec<String>, name: &str, value: usize) {
+    if value > MAX_SPAWN_COUNT {
+        problems.push(format!("{} must be at most {}, got {}",
+                            name, MAX_SPAWN_COUNT, value));
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn game_config_path() -> PathBuf {
+    bevy::asset::io::file::FileAssetReader::get_base_path()
+        .join("assets")
+        .join(GAME_CONFIG_PATH)
+}
+
+// there is no file system on the web, the defaults are used there
+#[cfg(target_arch = "wasm32")]
+fn game_config_path() -> PathBuf {
+    PathBuf::from(GAME_CONFIG_PATH)
+}
@@ -0,0 +1,57 @@
+
+use std::str::FromStr;
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy,
+        PartialEq, Eq, Hash, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Insane,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Insane,
+    ];
+
+    pub fn index(&self) -> u8 {
+        match self {
+            Difficulty::Easy => 0,
+            Difficulty::Normal => 1,
+            Difficulty::Hard => 2,
+            Difficulty::Insane => 3,
+        }
+    }
+
+    pub fn from_index(index: u8) -> Option<Difficulty> {
+        Difficulty::ALL.get(index as usize).copied()
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+            Difficulty::Insane => "Insane",
+        }
+    }
+}
+
+// accepts the labels in any case, e.g. `--difficulty hard`
+impl FromStr for Difficulty {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Difficulty::ALL.into_iter()
+            .find(|difficulty| difficulty.label().eq_ignore_ascii_case(value))
+            .ok_or(())
+    }
+}
@@ -1,7 +1,14 @@
-
 use bevy::prelude::{Component, Vec2};
 
 #[derive(Component)]
 pub struct Enemy {
     pub direction: Vec2,
-}
\ No newline at end of file
+    // index into the configured archetypes
+    pub archetype: usize,
+    // copied from the archetype with the difficulty applied,
+    // the speed changes for ones that accelerate
+    pub speed: f32,
+    pub size: f32,
+    pub mass: f32,
+    pub bounces: u32,
+}
@@ -6,21 +6,19 @@ pub mod resources;
 mod systems;
 
 use systems::*;
-use resources::*;
 
 use crate::AppState;
 use crate::game::SimulationState;
 
 use super::player::PlayerSystemSet;
-
-pub const NUM_OF_ENEMIES: usize = 4;
-pub const ENEMY_SPEED: f32 = 200.0;
-pub const ENEMY_SIZE: f32 = 64.0; // this is the enemy sprite size
+use super::physics::PhysicsSystemSet;
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub enum EnemySystemSet {
     Movment,
     Confinement,
+    Collision,
+    Spawning,
 }
 
 pub struct EnemyPlugin;
@@ -28,25 +26,39 @@ pub struct EnemyPlugin;
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app
-        .configure_sets(Update,
-             EnemySystemSet::Movment
-                .before(EnemySystemSet::Confinement))
-        .init_resource::<EnemySpawnTimer>()
-        .add_systems(OnEnter(AppState::Game), 
+        .configure_sets(FixedUpdate, (
+            EnemySystemSet::Movment
+                .after(PhysicsSystemSet::Snapshot),
+            EnemySystemSet::Confinement,
+            EnemySystemSet::Collision
+                .after(PhysicsSystemSet::BroadPhase),
+            EnemySystemSet::Spawning
+                .after(PlayerSystemSet::Collision)
+        ).chain())
+        .add_systems(OnEnter(AppState::Game), (
+            insert_enemy_spawn_timer,
             (apply_deferred.after(PlayerSystemSet::PlayerSpawn),
-                    spawn_enemies).chain())
-        .add_systems(Update, (
+                    spawn_enemies).chain()
+        ))
+        .add_systems(FixedUpdate, (
                     enemy_movement.in_set(EnemySystemSet::Movment), 
-                    update_enemy_direction.in_set(EnemySystemSet::Confinement),
-                    confine_enemy_movement.in_set(EnemySystemSet::Confinement),
-                    tick_enemy_spawn_timer,
-                    spawn_enemies_over_time
+                    (update_enemy_direction, confine_enemy_movement)
+                        .chain()
+                        .in_set(EnemySystemSet::Confinement),
+                    collide_enemies
+                        .run_if(enemy_collisions_enabled)
+                        .in_set(EnemySystemSet::Collision),
+                    (tick_enemy_spawn_timer, spawn_enemies_over_time)
+                        .chain()
+                        .in_set(EnemySystemSet::Spawning)
                 )
                 .run_if(in_state(AppState::Game))
                 .run_if(in_state(SimulationState::Running))
         )
-        .add_systems(OnExit(AppState::Game), 
-            enemy_despawn);
+        .add_systems(OnExit(AppState::Game), (
+            enemy_despawn,
+            remove_enemy_spawn_timer
+        ));
     }
 }
 
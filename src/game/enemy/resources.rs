@@ -1,17 +1,15 @@
 
 use bevy::prelude::{Resource, Timer, TimerMode};
 
-pub const ENEMY_SPAWN_TIME: f32 = 5.0;
-
 #[derive(Resource)]
 pub struct EnemySpawnTimer {
     pub timer: Timer,
 }
 
-impl Default for EnemySpawnTimer {
-    fn default() -> Self {
+impl EnemySpawnTimer {
+    pub fn new(spawn_time: f32) -> Self {
         EnemySpawnTimer {
-            timer: Timer::from_seconds(ENEMY_SPAWN_TIME,
+            timer: Timer::from_seconds(spawn_time,
                                  TimerMode::Repeating),
         }
     }
@@ -1,27 +1,41 @@
 
-use bevy::{prelude::*, window::PrimaryWindow};
-use rand::{random, Rng};
+use bevy::prelude::*;
+use rand::Rng;
 
 use crate::game::enemy::{
     components::*, 
     resources::*,
-    NUM_OF_ENEMIES,
-    ENEMY_SIZE,
-    ENEMY_SPEED,
     confine_actor_axis  
 };
 use crate::game::player::components::Player;
+use crate::game::config::{GameConfig, EnemyBehaviour};
+use crate::game::difficulty::Difficulty;
+use crate::game::rng::resources::{GameRng, RngStream};
+use crate::game::physics::{
+    components::{Position, Collider, CollisionLayer},
+    resources::SpatialGrid
+};
+use crate::game::resources::Arena;
+use crate::game::power_up::resources::ActivePowerUps;
+use crate::events::{EnemyBounced, EnemySpawned};
+
+// how far each half of a split enemy turns away from the original path
+const SPLIT_ANGLE: f32 = 0.5;
 
 pub fn spawn_enemies(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-    player_query: Query<&Transform, With<Player>>
+    arena: Res<Arena>,
+    player_query: Query<&Position, With<Player>>,
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>,
+    mut game_rng: ResMut<GameRng>,
+    mut enemy_spawned_event_writer: EventWriter<EnemySpawned>
 ) {
-    let window = window_query.get_single().unwrap();
+    let rng = game_rng.stream(RngStream::Enemy);
 
-    (0..NUM_OF_ENEMIES).for_each(|_| {
-        enemy_spawn(window, &mut commands, &asset_server, &player_query);
+    (0..config.enemy_count(*difficulty)).for_each(|_| {
+        enemy_spawn(&arena, &mut commands, &mut enemy_spawned_event_writer,
+                    &player_query, &config, *difficulty, rng);
     });
 }
 
@@ -34,31 +48,159 @@ pub fn enemy_despawn(
     });
 }
 
+pub fn insert_enemy_spawn_timer(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>
+) {
+    let spawn_time = config.enemy_spawn_time(*difficulty);
+
+    commands.insert_resource(EnemySpawnTimer::new(spawn_time));
+}
+
+pub fn remove_enemy_spawn_timer(mut commands: Commands) {
+    commands.remove_resource::<EnemySpawnTimer>();
+}
+
 pub fn enemy_movement(
-    mut enemy_query: Query<(&mut Transform, &Enemy)>,
-    time: Res<Time>
+    mut enemy_query: Query<(&mut Position, &mut Enemy)>,
+    player_query: Query<&Position, (With<Player>, Without<Enemy>)>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>,
+    active_power_ups: Res<ActivePowerUps>
 ) {
-    for (mut transform, eneny) in enemy_query.iter_mut() {
-        let direction = Vec3::new(eneny.direction.x, eneny.direction.y, 0.0);
-        transform.translation += direction * ENEMY_SPEED * time.delta_seconds();
+    let delta = time.delta_seconds();
+    let speed_scale = active_power_ups.enemy_speed_scale(&config.power_up);
+    let player_position = player_query.get_single()
+        .ok()
+        .map(|player_position| player_position.current);
+
+    for (mut position, mut enemy) in enemy_query.iter_mut() {
+        match config.enemy.archetypes[enemy.archetype].behaviour {
+            EnemyBehaviour::Homing { turn_rate } => {
+                if let Some(player_position) = player_position {
+                    let max_turn = turn_rate * delta;
+                    let turn = enemy.direction
+                        .angle_between(player_position - position.current);
+
+                    // nan when the enemy sits right on the player
+                    if turn.is_finite() {
+                        enemy.direction = Vec2::from_angle(turn.clamp(-max_turn, max_turn))
+                            .rotate(enemy.direction);
+                    }
+                }
+            },
+            EnemyBehaviour::Accelerating { acceleration, max_speed } => {
+                let max_speed = config.enemy_speed(*difficulty, max_speed);
+                enemy.speed = (enemy.speed
+                    + config.enemy_speed(*difficulty, acceleration) * delta)
+                    .min(max_speed);
+            },
+            EnemyBehaviour::Bouncer | EnemyBehaviour::Splitter { .. } => {},
+        }
+
+        position.current += enemy.direction * enemy.speed * speed_scale * delta;
     }
 }
 
+pub fn enemy_collisions_enabled(
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>
+) -> bool {
+    config.enemy_collisions(*difficulty)
+}
+
+// elastic collisions between every pair of touching enemies, overlaps are
+// pushed apart so they never stick together. pairs come from the spatial grid,
+// one pushed into another by an earlier pair is caught next tick
+pub fn collide_enemies(
+    mut enemy_query: Query<(Entity, &mut Position, &mut Enemy, &Collider)>,
+    spatial_grid: Res<SpatialGrid>,
+    mut enemy_bounced_event_writer: EventWriter<EnemyBounced>
+) {
+    // every pair once, the lower entity first
+    let pairs: Vec<[Entity; 2]> = enemy_query.iter()
+        .flat_map(|(entity, position, _, collider)| {
+            spatial_grid.query(position.current, collider.shape.bounding_radius())
+                .filter(move |entry| entry.entity > entity)
+                .filter(|entry| enemy_query.get(entry.entity)
+                    .is_ok_and(|(_, _, _, other)| collider.interacts_with(other)))
+                .map(move |entry| [entity, entry.entity])
+        })
+        .collect();
+
+    pairs.into_iter().for_each(|pair| {
+        let Ok([
+            (_, mut position_a, mut enemy_a, collider_a),
+            (_, mut position_b, mut enemy_b, collider_b)
+        ]) = enemy_query.get_many_mut(pair) else {
+            return;
+        };
+
+        let offset = position_b.current - position_a.current;
+        let distance = offset.length();
+        let overlap = collider_a.shape.bounding_radius()
+            + collider_b.shape.bounding_radius() - distance;
+
+        if overlap <= 0.0 {
+            return;
+        }
+
+        // enemies right on top of each other are split sideways
+        let normal = offset.try_normalize().unwrap_or(Vec2::X);
+        let total_mass = enemy_a.mass + enemy_b.mass;
+
+        position_a.current -= normal * overlap * enemy_b.mass / total_mass;
+        position_b.current += normal * overlap * enemy_a.mass / total_mass;
+
+        let velocity_a = enemy_a.direction * enemy_a.speed;
+        let velocity_b = enemy_b.direction * enemy_b.speed;
+        let closing_speed = (velocity_a - velocity_b).dot(normal);
+
+        // already moving apart, the separation above was enough
+        if closing_speed <= 0.0 {
+            return;
+        }
+
+        let impulse = 2.0 * closing_speed / total_mass;
+        set_velocity(&mut enemy_a, velocity_a - normal * impulse * enemy_b.mass);
+        set_velocity(&mut enemy_b, velocity_b + normal * impulse * enemy_a.mass);
+
+        // one event for the pair, where their edges meet
+        enemy_bounced_event_writer.send(EnemyBounced {
+            enemy: pair[0],
+            other: Some(pair[1]),
+            position: position_a.current
+                + normal * collider_a.shape.bounding_radius(),
+        });
+    });
+}
+
+fn set_velocity(enemy: &mut Enemy, velocity: Vec2) {
+    // a head on hit can leave one at a standstill, it keeps its heading
+    if let Some(direction) = velocity.try_normalize() {
+        enemy.direction = direction;
+    }
+    enemy.speed = velocity.length();
+}
+
 pub fn update_enemy_direction(
     mut commands: Commands,
-    mut enemy_query: Query<(&Transform, &mut Enemy)>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>
+    mut enemy_query: Query<(Entity, &Position, &mut Enemy)>,
+    arena: Res<Arena>,
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>,
+    mut enemy_bounced_event_writer: EventWriter<EnemyBounced>,
+    mut enemy_spawned_event_writer: EventWriter<EnemySpawned>
 ) {
-    let window = window_query.get_single().unwrap();
-    let half_enemy_size = ENEMY_SIZE / 2.0;
-    let min_x = half_enemy_size;
-    let max_x = window.width() - half_enemy_size;
-    let min_y = half_enemy_size;
-    let max_y = window.height() - half_enemy_size;
-    
-    for (transform, mut enemy) in enemy_query.iter_mut() {
-        let translation = transform.translation;
+    for (enemy_entity, position, mut enemy) in enemy_query.iter_mut() {
+        let half_enemy_size = enemy.size / 2.0;
+        let min_x = half_enemy_size;
+        let max_x = arena.width() - half_enemy_size;
+        let min_y = half_enemy_size;
+        let max_y = arena.height() - half_enemy_size;
+        let translation = position.current;
         let mut is_direction_changed = false;
         
         if translation.x <= min_x || translation.x >= max_x {
@@ -70,37 +212,46 @@ pub fn update_enemy_direction(
             is_direction_changed = true;
         }
         
-        if is_direction_changed {
-            let sound_effect_1 = asset_server.load("audio/pluck_001.ogg");
-            let sound_effect_2 = asset_server.load("audio/pluck_002.ogg");
-            
-            let sound_effect = if random::<bool>() {
-                sound_effect_1
-            } else {
-                sound_effect_2
-            };
-            
-            commands.spawn(AudioBundle {
-                source: sound_effect,
-                settings: PlaybackSettings::DESPAWN,
-            });
+        if !is_direction_changed {
+            continue;
+        }
+
+        enemy_bounced_event_writer.send(EnemyBounced {
+            enemy: enemy_entity,
+            other: None,
+            position: translation,
+        });
+        enemy.bounces += 1;
+
+        if let EnemyBehaviour::Splitter { bounces, into } =
+            &config.enemy.archetypes[enemy.archetype].behaviour {
+            if enemy.bounces >= *bounces {
+                let Some(into) = config.archetype_index(into) else {
+                    continue;
+                };
+
+                commands.entity(enemy_entity).despawn();
+                [-SPLIT_ANGLE, SPLIT_ANGLE].iter().for_each(|angle| {
+                    let direction = Vec2::from_angle(*angle).rotate(enemy.direction);
+                    spawn_enemy(&mut commands, &mut enemy_spawned_event_writer,
+                                &config, *difficulty, into, translation, direction);
+                });
+            }
         }
     }
 }
 
 pub fn confine_enemy_movement(
-    mut enemy_query: Query<&mut Transform, With<Enemy>>,
-    window_query: Query<&Window, With<PrimaryWindow>>
+    mut enemy_query: Query<(&mut Position, &Enemy)>,
+    arena: Res<Arena>
 ) {
-    let window = window_query.get_single().unwrap();
-    
-    for mut enemy_transform in enemy_query.iter_mut() {
-        let mut translation = enemy_transform.translation;
+    for (mut enemy_position, enemy) in enemy_query.iter_mut() {
+        let mut position = enemy_position.current;
         
-        confine_actor_axis(&mut translation.x, window.width(), ENEMY_SIZE);
-        confine_actor_axis(&mut translation.y, window.height(), ENEMY_SIZE);
+        confine_actor_axis(&mut position.x, arena.width(), enemy.size);
+        confine_actor_axis(&mut position.y, arena.height(), enemy.size);
         
-        enemy_transform.translation = translation;
+        enemy_position.current = position;
     }
     
 }
@@ -114,53 +265,101 @@ pub fn tick_enemy_spawn_timer(
 
 pub fn spawn_enemies_over_time(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    arena: Res<Arena>,
     enemy_spawn_timer: Res<EnemySpawnTimer>,
-    player_query: Query<&Transform, With<Player>>
+    player_query: Query<&Position, With<Player>>,
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>,
+    mut game_rng: ResMut<GameRng>,
+    mut enemy_spawned_event_writer: EventWriter<EnemySpawned>
 ) {
     if enemy_spawn_timer.timer.finished() {
-        let window = window_query.get_single().unwrap();
-        enemy_spawn(window, &mut commands, &asset_server, &player_query);
+        let rng = game_rng.stream(RngStream::Enemy);
+        enemy_spawn(&arena, &mut commands, &mut enemy_spawned_event_writer,
+                    &player_query, &config, *difficulty, rng);
     }
 }
 
 
 fn enemy_spawn(
-    window: &Window,
+    arena: &Arena,
     commands: &mut Commands,
-    asset_server: &Res<AssetServer>,
-    player_query: &Query<&Transform, With<Player>>
+    enemy_spawned_event_writer: &mut EventWriter<EnemySpawned>,
+    player_query: &Query<&Position, With<Player>>,
+    config: &GameConfig,
+    difficulty: Difficulty,
+    rng: &mut impl Rng
 ) {
-    if let Ok(player_transform) = player_query.get_single() {
-        let player_translation = player_transform.translation;
-        let window_width = window.width();
-        let window_height = window.height();
-        let mut enemy_pos = Vec3::new(random::<f32>() * window_width, 
-                                random::<f32>() * window_height, 0.0);        
+    if let Ok(player_position) = player_query.get_single() {
+        let archetype = pick_archetype(config, rng);
+        let size = config.enemy.archetypes[archetype].size;
+        let player_translation = player_position.current;
+        let arena_width = arena.width();
+        let arena_height = arena.height();
+        let mut enemy_pos = Vec2::new(rng.gen::<f32>() * arena_width, 
+                                rng.gen::<f32>() * arena_height);        
         let mut fail_safe = 100;
-        let mut rng = rand::thread_rng();
 
-        while (player_translation.distance(enemy_pos) < (ENEMY_SIZE * 2.0))
+        while (player_translation.distance(enemy_pos) < (size * 2.0))
                 && (fail_safe > 0) {
-            enemy_pos.x = rng.gen::<f32>() * window_width;
-            enemy_pos.y = rng.gen::<f32>() * window_height;
+            enemy_pos.x = rng.gen::<f32>() * arena_width;
+            enemy_pos.y = rng.gen::<f32>() * arena_height;
             
             fail_safe -= 1;
         }
 
         if fail_safe > 0 {
-            commands.spawn((
-                SpriteBundle {
-                    transform: Transform::from_translation(enemy_pos),
-                    texture: asset_server.load("sprites/ball_red_large.png"),
-                    ..default()
-                },
-                Enemy {
-                    direction: Vec2::new(random::<f32>(), random::<f32>()).normalize(),
-                }
-            ));
+            let direction = Vec2::new(rng.gen::<f32>(), rng.gen::<f32>()).normalize();
+            spawn_enemy(commands, enemy_spawned_event_writer, config, difficulty,
+                        archetype, enemy_pos, direction);
         }
 
     }
-}
\ No newline at end of file
+}
+
+// weighted by each archetype's weight, validation makes sure one is above zero
+fn pick_archetype(config: &GameConfig, rng: &mut impl Rng) -> usize {
+    let archetypes = &config.enemy.archetypes;
+    let total: f32 = archetypes.iter().map(|archetype| archetype.weight).sum();
+    let mut roll = rng.gen::<f32>() * total;
+
+    archetypes.iter()
+        .position(|archetype| {
+            roll -= archetype.weight;
+            archetype.weight > 0.0 && roll < 0.0
+        })
+        .or_else(|| archetypes.iter().rposition(|archetype| archetype.weight > 0.0))
+        .unwrap_or(0)
+}
+
+fn spawn_enemy(
+    commands: &mut Commands,
+    enemy_spawned_event_writer: &mut EventWriter<EnemySpawned>,
+    config: &GameConfig,
+    difficulty: Difficulty,
+    archetype: usize,
+    position: Vec2,
+    direction: Vec2
+) {
+    let enemy_archetype = &config.enemy.archetypes[archetype];
+
+    let enemy_entity = commands.spawn((
+        Position::new(position),
+        Enemy {
+            direction,
+            archetype,
+            speed: config.enemy_speed(difficulty, enemy_archetype.speed),
+            size: enemy_archetype.size,
+            mass: enemy_archetype.mass,
+            bounces: 0,
+        },
+        Collider::circle(enemy_archetype.radius, CollisionLayer::ENEMY,
+                        CollisionLayer::PLAYER | CollisionLayer::ENEMY)
+    )).id();
+
+    enemy_spawned_event_writer.send(EnemySpawned {
+        enemy: enemy_entity,
+        archetype,
+        position,
+    });
+}
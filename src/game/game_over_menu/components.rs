@@ -11,4 +11,13 @@ pub struct NewGameButton {}
 pub struct QuitButton {}
 
 #[derive(Component)]
-pub struct MainMenuButton {}
\ No newline at end of file
+pub struct MainMenuButton {}
+
+#[derive(Component)]
+pub struct NameEntry {}
+
+#[derive(Component)]
+pub struct NameInput {}
+
+#[derive(Component)]
+pub struct ConfirmNameButton {}
\ No newline at end of file
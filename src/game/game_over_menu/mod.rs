@@ -1,11 +1,12 @@
 
-mod components;
+pub mod components;
 mod systems;
 
 use bevy::prelude::*;
 
 use systems::*;
 use crate::AppState;
+use crate::game::score::resources::PendingHighScore;
 
 pub struct GameOverMenuPlugin;
 
@@ -19,7 +20,15 @@ impl Plugin for GameOverMenuPlugin {
                     interact_with_main_menu_button,
                     interact_with_quit_button
                 )
+                .run_if(in_state(AppState::GameOver)),
+                (
+                    edit_player_name,
+                    update_name_input,
+                    interact_with_confirm_name_button
+                )
+                .chain()
                 .run_if(in_state(AppState::GameOver))
+                .run_if(resource_exists::<PendingHighScore>())
         ))
         .add_systems(OnExit(AppState::GameOver),
                     despawn_game_over_menu);
@@ -3,15 +3,23 @@ use bevy::{prelude::*, app::AppExit};
 
 use super::components::*;
 use crate::{
-    main_menu::styles::*, 
+    main_menu::styles::*,
     events::GameOver,
+    game::score::resources::{HighScores, PendingHighScore, MAX_NAME_LENGTH},
+    game::rng::resources::GameRng,
+    controls::resources::InputAction,
     AppState,
 };
+use crate::loading::resources::GameAssets;
+
+const NAME_INPUT_COLOR: Color = Color::rgba(0.1, 0.1, 0.1, 0.9);
 
 pub fn spawn_game_over_menu(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut game_over_event_reader: EventReader<GameOver>
+    game_assets: Res<GameAssets>,
+    mut game_over_event_reader: EventReader<GameOver>,
+    high_scores: Res<HighScores>,
+    game_rng: Res<GameRng>
 ) {
     game_over_event_reader.read().for_each(|event| {
         commands.spawn(
@@ -33,8 +41,7 @@ pub fn spawn_game_over_menu(
                 parent.spawn(
                     ImageBundle {
                         style: IMAGE_STYLE,
-                        image: asset_server
-                        .load("sprites/ball_blue_large.png")
+                        image: game_assets.player_sprite.clone()
                         .into(),
                         ..default()
                     }
@@ -47,7 +54,7 @@ pub fn spawn_game_over_menu(
                                 TextSection::new(
                                     format!("Game Over!\n final score: {}", 
                                             event.score),
-                                    get_title_text_style(&asset_server)
+                                    get_title_text_style(&game_assets)
                                 )],
                                 alignment: TextAlignment::Center,
                                 ..default()
@@ -60,13 +67,44 @@ pub fn spawn_game_over_menu(
                     parent.spawn(
                         ImageBundle {
                             style: IMAGE_STYLE,
-                    image: asset_server
-                    .load("sprites/ball_red_large.png")
+                    image: game_assets.enemy_sprite.clone()
                     .into(),
                     ..default()
                 }
             );
         });
+        // === Best Combo ===
+        parent.spawn(
+            TextBundle {
+                text: Text {
+                    sections: vec![
+                        TextSection::new(
+                            format!("Best combo: {}", event.best_combo),
+                            get_small_button_text_style(&game_assets)
+                        )],
+                    alignment: TextAlignment::Center,
+                    ..default()
+                },
+                ..default()
+            });
+        // === Seed ===
+        parent.spawn(
+            TextBundle {
+                text: Text {
+                    sections: vec![
+                        TextSection::new(
+                            format!("Seed: {}", game_rng.seed),
+                            get_small_button_text_style(&game_assets)
+                        )],
+                    alignment: TextAlignment::Center,
+                    ..default()
+                },
+                ..default()
+            });
+        // === Name Entry ===
+        if high_scores.qualifies(event.score) {
+            spawn_name_entry(parent, &game_assets, &high_scores.last_name);
+        }
         // === New Game Button ===
         parent.spawn((
             ButtonBundle {
@@ -83,7 +121,7 @@ pub fn spawn_game_over_menu(
                         sections: vec![
                             TextSection::new(
                                 "New Game", 
-                                get_button_text_style(&asset_server)
+                                get_button_text_style(&game_assets)
                             )],
                             alignment: TextAlignment::Center,
                             ..default()
@@ -107,7 +145,7 @@ pub fn spawn_game_over_menu(
                         sections: vec![
                             TextSection::new(
                                 "Main Menu", 
-                                get_button_text_style(&asset_server)
+                                get_button_text_style(&game_assets)
                             )],
                             alignment: TextAlignment::Center,
                             ..default()
@@ -131,7 +169,7 @@ pub fn spawn_game_over_menu(
                         sections: vec![
                             TextSection::new(
                                 "Quit", 
-                                get_button_text_style(&asset_server)
+                                get_button_text_style(&game_assets)
                             )],
                         alignment: TextAlignment::Center,
                         ..default()
@@ -153,6 +191,171 @@ pub fn despawn_game_over_menu(
     }
 }
 
+fn spawn_name_entry(
+    parent: &mut ChildBuilder,
+    game_assets: &Res<GameAssets>,
+    default_name: &str
+) {
+    parent.spawn((
+        NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ..default()
+        },
+        NameEntry {}
+    ))
+    .with_children(|parent| {
+        // Label
+        parent.spawn(
+            TextBundle {
+                text: Text {
+                    sections: vec![
+                        TextSection::new(
+                            "New high score! Name:",
+                            get_button_text_style(game_assets)
+                        )],
+                    alignment: TextAlignment::Center,
+                    ..default()
+                },
+                ..default()
+            });
+        // Input
+        parent.spawn(
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(300.0),
+                    height: Val::Px(60.0),
+                    padding: UiRect::horizontal(Val::Px(8.0)),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: NAME_INPUT_COLOR.into(),
+                ..default()
+            }
+        )
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle {
+                    text: Text {
+                        sections: vec![
+                            TextSection::new(
+                                name_input_text(default_name),
+                                get_button_text_style(game_assets)
+                            )],
+                        ..default()
+                    },
+                    ..default()
+                },
+                NameInput {}
+            ));
+        });
+        // Confirm Button
+        parent.spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(120.0),
+                    height: Val::Px(60.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: NORMAL_BUTTON_COLOR.into(),
+                ..default()
+            },
+            ConfirmNameButton {}
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+                TextBundle {
+                    text: Text {
+                        sections: vec![
+                            TextSection::new(
+                                "OK",
+                                get_button_text_style(game_assets)
+                            )],
+                        alignment: TextAlignment::Center,
+                        ..default()
+                    },
+                    ..default()
+                });
+        });
+    });
+}
+
+fn name_input_text(name: &str) -> String {
+    if name.chars().count() < MAX_NAME_LENGTH {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+pub fn edit_player_name(
+    mut received_character_reader: EventReader<ReceivedCharacter>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mut pending_high_score: ResMut<PendingHighScore>
+) {
+    let name = &mut pending_high_score.name;
+
+    received_character_reader.read().for_each(|event| {
+        if !event.char.is_control() && name.chars().count() < MAX_NAME_LENGTH {
+            name.push(event.char);
+        }
+    });
+
+    if keyboard_input.just_pressed(KeyCode::Back) {
+        name.pop();
+    }
+}
+
+pub fn update_name_input(
+    pending_high_score: Res<PendingHighScore>,
+    mut text_query: Query<&mut Text, With<NameInput>>
+) {
+    if pending_high_score.is_changed() {
+        if let Ok(mut text) = text_query.get_single_mut() {
+            text.sections[0].value = name_input_text(&pending_high_score.name);
+        }
+    }
+}
+
+pub fn interact_with_confirm_name_button(
+    mut commands: Commands,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor),
+                        (Changed<Interaction>, With<ConfirmNameButton>)>,
+    action_input: Res<Input<InputAction>>,
+    name_entry_query: Query<Entity, With<NameEntry>>,
+    pending_high_score: Res<PendingHighScore>,
+    mut high_scores: ResMut<HighScores>
+) {
+    let mut confirmed = action_input.just_pressed(InputAction::Confirm);
+
+    if let Ok((interaction, mut background_color)) =
+                button_query.get_single_mut() {
+                    *background_color = match *interaction {
+                    Interaction::Pressed => {
+                        confirmed = true;
+                        PRESSED_BUTTON_COLOR.into()
+                    },
+                    Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+                    Interaction::None => NORMAL_BUTTON_COLOR.into(),
+                }
+    }
+
+    if confirmed {
+        high_scores.submit(&pending_high_score);
+        commands.remove_resource::<PendingHighScore>();
+
+        if let Ok(entity) = name_entry_query.get_single() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
 
 pub fn interact_with_new_game_button(
     mut button_query: Query<(&Interaction, &mut BackgroundColor), 
@@ -1,6 +1,9 @@
 
-use bevy::prelude::Component;
+use bevy::prelude::{Color, Component, Timer, TimerMode};
 
+use crate::game::power_up::resources::PowerUpKind;
+
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Component)]
 pub struct HUD {}
 
@@ -8,4 +11,42 @@ pub struct HUD {}
 pub struct ScoreDisplay {}
 
 #[derive(Component)]
-pub struct EnemyCounter {}
\ No newline at end of file
+pub struct LivesDisplay {}
+
+#[derive(Component)]
+pub struct EnemyCounter {}
+
+#[derive(Component)]
+pub struct DifficultyDisplay {}
+// shown while a combo is going, hidden otherwise
+#[derive(Component)]
+pub struct ComboDisplay {}
+
+#[derive(Component)]
+pub struct ComboText {}
+
+// shrinks as the combo's window runs out
+#[derive(Component)]
+pub struct ComboTimerBar {}
+
+// shown while a power-up is running, hidden otherwise
+#[derive(Component)]
+pub struct PowerUpIndicator {
+    pub kind: PowerUpKind,
+}
+
+// text lit up in a color that fades back to white
+#[derive(Component)]
+pub struct HudFlash {
+    pub color: Color,
+    pub timer: Timer,
+}
+
+impl HudFlash {
+    pub fn new(color: Color, seconds: f32) -> Self {
+        HudFlash {
+            color,
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+}
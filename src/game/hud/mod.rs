@@ -1,5 +1,5 @@
 
-mod components;
+pub mod components;
 mod systems;
 
 use bevy::prelude::*;
@@ -17,7 +17,11 @@ impl Plugin for HUDPlugin {
             spawn_hud)
         .add_systems(Update, (
                     update_score_display,
-                    update_enemy_counter
+                    update_lives_display,
+                    update_power_up_indicators,
+                    update_combo_display,
+                    update_enemy_counter,
+                    (flash_hud, fade_hud_flashes).chain()
                 )
                 .run_if(in_state(AppState::Game))
         )
@@ -3,15 +3,28 @@ use bevy::prelude::*;
 
 use super::components::*;
 use crate::game::{
-    score::resources::Score, 
-    enemy::components::Enemy
+    score::resources::{Score, Combo},
+    config::GameConfig,
+    player::resources::Lives,
+    enemy::components::Enemy,
+    difficulty::Difficulty,
+    power_up::resources::{ActivePowerUps, PowerUpKind}
 };
+use crate::loading::resources::GameAssets;
+use crate::events::{StarCollected, PlayerHit};
 
 const BACKGROUND_COLOR: Color = Color::rgba(0.5, 0.5, 0.5, 0.25);
+const STAR_COLOR: Color = Color::rgb(1.0, 0.85, 0.2);
+const HIT_FLASH_COLOR: Color = Color::rgb(1.0, 0.2, 0.2);
+const FLASH_TIME: f32 = 0.4;
+const COMBO_BAR_WIDTH: f32 = 160.0;
+const COMBO_BAR_HEIGHT: f32 = 6.0;
+const COMBO_BAR_BACKGROUND_COLOR: Color = Color::rgba(0.1, 0.1, 0.1, 0.75);
 
 pub fn spawn_hud(
     mut commands: Commands,
-    asset_server: Res<AssetServer>
+    game_assets: Res<GameAssets>,
+    difficulty: Res<Difficulty>
 ) {
     commands.spawn((
         NodeBundle {
@@ -33,7 +46,7 @@ pub fn spawn_hud(
         parent.spawn(
             NodeBundle {
                 style: Style {
-                    width: Val::Px(128.0),
+                    width: Val::Px(256.0),
                     height: Val::Px(64.0),
                     flex_direction: FlexDirection::Row,
                     align_content: AlignContent::Center,
@@ -61,7 +74,7 @@ pub fn spawn_hud(
                         
                         ..default()
                     },
-                    image: asset_server.load("sprites/star.png").into(),
+                    image: game_assets.star_sprite.clone().into(),
                     ..default()
                 }
             );
@@ -72,7 +85,7 @@ pub fn spawn_hud(
                         sections: vec![
                             TextSection::new(
                                 " : ",
-                                get_text_style(&asset_server)
+                                get_text_style(&game_assets)
                             )],
                         alignment: TextAlignment::Center,
                         ..default()
@@ -81,6 +94,184 @@ pub fn spawn_hud(
                 },
                 ScoreDisplay {}
             ));
+            // === Lives ===
+            parent.spawn(
+                ImageBundle {
+                    style: Style {
+                        width: Val::Px(32.0),
+                        height: Val::Px(32.0),
+                        margin: UiRect::all(Val::Px(4.0)),
+
+                        ..default()
+                    },
+                    image: game_assets.player_sprite.clone().into(),
+                    ..default()
+                }
+            );
+            parent.spawn((
+                TextBundle {
+                    text: Text {
+                        sections: vec![
+                            TextSection::new(
+                                " : ",
+                                get_text_style(&game_assets)
+                            )],
+                        alignment: TextAlignment::Center,
+                        ..default()
+                    },
+                    ..default()
+                },
+                LivesDisplay {}
+            ));
+        });
+        parent.spawn(
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+
+                    ..default()
+                },
+                ..default()
+            }
+        )
+        .with_children(|parent| {
+            // === Difficulty ===
+            parent.spawn(
+                NodeBundle {
+                    style: Style {
+                        height: Val::Px(64.0),
+                        padding: UiRect::horizontal(Val::Px(16.0)),
+                        align_items: AlignItems::Center,
+                        margin: UiRect {
+                            top: Val::Px(4.0),
+                            ..default()
+                        },
+
+                        ..default()
+                    },
+                    background_color: BACKGROUND_COLOR.into(),
+                    ..default()
+                }
+            )
+            .with_children(|parent| {
+                parent.spawn((
+                    TextBundle {
+                        text: Text {
+                            sections: vec![
+                                TextSection::new(
+                                    difficulty.label(),
+                                    get_text_style(&game_assets)
+                                )],
+                            alignment: TextAlignment::Center,
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    DifficultyDisplay {}
+                ));
+            });
+            // === Combo ===
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        display: Display::None,
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(4.0)),
+                        margin: UiRect {
+                            top: Val::Px(4.0),
+                            ..default()
+                        },
+
+                        ..default()
+                    },
+                    background_color: BACKGROUND_COLOR.into(),
+                    ..default()
+                },
+                ComboDisplay {}
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    TextBundle {
+                        text: Text::from_section(
+                            "",
+                            TextStyle {
+                                color: STAR_COLOR,
+                                ..get_text_style(&game_assets)
+                            }
+                        ),
+                        ..default()
+                    },
+                    ComboText {}
+                ));
+                // === Timer Bar ===
+                parent.spawn(
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(COMBO_BAR_WIDTH),
+                            height: Val::Px(COMBO_BAR_HEIGHT),
+
+                            ..default()
+                        },
+                        background_color: COMBO_BAR_BACKGROUND_COLOR.into(),
+                        ..default()
+                    }
+                )
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(100.0),
+
+                                ..default()
+                            },
+                            background_color: STAR_COLOR.into(),
+                            ..default()
+                        },
+                        ComboTimerBar {}
+                    ));
+                });
+            });
+            // === Power-Ups ===
+            parent.spawn(
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(16.0),
+                        margin: UiRect {
+                            top: Val::Px(4.0),
+                            ..default()
+                        },
+
+                        ..default()
+                    },
+                    ..default()
+                }
+            )
+            .with_children(|parent| {
+                PowerUpKind::ALL.iter().for_each(|kind| {
+                    parent.spawn((
+                        TextBundle {
+                            style: Style {
+                                display: Display::None,
+                                ..default()
+                            },
+                            text: Text::from_section(
+                                kind.label(),
+                                TextStyle {
+                                    color: kind.color(),
+                                    ..get_text_style(&game_assets)
+                                }
+                            ),
+                            background_color: BACKGROUND_COLOR.into(),
+                            ..default()
+                        },
+                        PowerUpIndicator { kind: *kind }
+                    ));
+                });
+            });
         });
         // === Enemy Counter ===
         parent.spawn(
@@ -115,8 +306,7 @@ pub fn spawn_hud(
                         
                         ..default()
                     },
-                    image: asset_server
-                    .load("sprites/ball_red_large.png").into(),
+                    image: game_assets.enemy_sprite.clone().into(),
                     
                     ..default()
                 }
@@ -128,7 +318,7 @@ pub fn spawn_hud(
                         sections: vec![
                             TextSection::new(
                                 " : ",
-                                get_text_style(&asset_server)
+                                get_text_style(&game_assets)
                             )],
                         alignment: TextAlignment::Center,
                         ..default()
@@ -159,6 +349,113 @@ pub fn update_score_display(
     }
 }
 
+pub fn update_lives_display(
+    lives: Res<Lives>,
+    mut text_query: Query<&mut Text, With<LivesDisplay>>
+) {
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = format!(" : {}", lives.remaining);
+    }
+}
+
+pub fn update_power_up_indicators(
+    active_power_ups: Res<ActivePowerUps>,
+    mut indicator_query: Query<(&mut Text, &mut Style, &PowerUpIndicator)>
+) {
+    indicator_query.for_each_mut(|(mut text, mut style, indicator)| {
+        let Some(timer) = active_power_ups.timer(indicator.kind) else {
+            if style.display != Display::None {
+                style.display = Display::None;
+            }
+            return;
+        };
+
+        let seconds_left = timer.remaining_secs().ceil();
+        text.sections[0].value = match indicator.kind {
+            PowerUpKind::Shield => format!("{} x{} {}s", indicator.kind.label(),
+                                        active_power_ups.shield_charges, seconds_left),
+            PowerUpKind::Bomb => format!("{}!", indicator.kind.label()),
+            _ => format!("{} {}s", indicator.kind.label(), seconds_left),
+        };
+        if style.display != Display::Flex {
+            style.display = Display::Flex;
+        }
+    });
+}
+
+pub fn update_combo_display(
+    combo: Res<Combo>,
+    config: Res<GameConfig>,
+    mut display_query: Query<&mut Style, (With<ComboDisplay>, Without<ComboTimerBar>)>,
+    mut text_query: Query<&mut Text, With<ComboText>>,
+    mut bar_query: Query<&mut Style, (With<ComboTimerBar>, Without<ComboDisplay>)>
+) {
+    let Ok(mut display_style) = display_query.get_single_mut() else {
+        return;
+    };
+
+    if combo.count == 0 {
+        if display_style.display != Display::None {
+            display_style.display = Display::None;
+        }
+        return;
+    }
+
+    if display_style.display != Display::Flex {
+        display_style.display = Display::Flex;
+    }
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = format!("Combo {} x{}", combo.count,
+                                        combo.multiplier(&config.combo));
+    }
+    if let Ok(mut bar_style) = bar_query.get_single_mut() {
+        bar_style.width = Val::Percent(combo.time_left_fraction() * 100.0);
+    }
+}
+
+// the score lights up for a star, the lives for a hit that cost one
+pub fn flash_hud(
+    mut commands: Commands,
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    score_query: Query<Entity, With<ScoreDisplay>>,
+    lives_query: Query<Entity, With<LivesDisplay>>
+) {
+    let stars = star_collected_event_reader.read().count();
+    let hits = player_hit_event_reader.read()
+        .filter(|event| !event.absorbed)
+        .count();
+
+    if stars > 0 {
+        score_query.for_each(|score_entity| {
+            commands.entity(score_entity).insert(HudFlash::new(STAR_COLOR, FLASH_TIME));
+        });
+    }
+    if hits > 0 {
+        lives_query.for_each(|lives_entity| {
+            commands.entity(lives_entity).insert(HudFlash::new(HIT_FLASH_COLOR, FLASH_TIME));
+        });
+    }
+}
+
+pub fn fade_hud_flashes(
+    mut commands: Commands,
+    mut flash_query: Query<(Entity, &mut Text, &mut HudFlash)>,
+    time: Res<Time>
+) {
+    flash_query.for_each_mut(|(entity, mut text, mut flash)| {
+        flash.timer.tick(time.delta());
+
+        let color = Vec4::from(flash.color).lerp(Vec4::from(Color::WHITE),
+                                                flash.timer.percent());
+        text.sections[0].style.color = Color::from(color);
+
+        if flash.timer.finished() {
+            commands.entity(entity).remove::<HudFlash>();
+        }
+    });
+}
+
 pub fn update_enemy_counter(
     mut text_query: Query<&mut Text, With<EnemyCounter>>,
     enemy_query: Query<With<Enemy>>
@@ -169,9 +466,9 @@ pub fn update_enemy_counter(
     }
 }
 
-fn get_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+fn get_text_style(game_assets: &Res<GameAssets>) -> TextStyle {
     TextStyle {
-        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font: game_assets.font.clone(),
         font_size: 32.0,
         color: Color::WHITE,
     }
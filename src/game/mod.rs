@@ -1,47 +1,125 @@
 
 use bevy::prelude::*;
 
-mod enemy;
-mod player;
-mod score;
-mod star;
+pub mod config;
+pub mod difficulty;
+pub mod enemy;
+pub mod player;
+pub mod physics;
+pub mod particles;
+pub mod power_up;
+pub mod rng;
+pub mod replay;
+pub mod resources;
+pub mod score;
+pub mod star;
+pub mod stats;
+pub mod sound;
+mod sprites;
 mod systems;
-mod pause_menu;
-mod game_over_menu;
-mod hud;
+pub mod pause_menu;
+pub mod game_over_menu;
+pub mod hud;
 
 use enemy::EnemyPlugin;
 use player::PlayerPlugin;
-use score::ScorePlugin;
+use physics::PhysicsPlugin;
+use particles::ParticlesPlugin;
+use power_up::PowerUpPlugin;
+use rng::RngPlugin;
+use replay::{ReplayPlugin, ReplayRecorderPlugin};
+use score::{ScorePlugin, HighScoresPlugin};
 use star::StarPlugin;
+use stats::StatsPlugin;
+use sound::SoundPlugin;
+use sprites::SpritesPlugin;
 use pause_menu::PauseMenuPlugin;
 use game_over_menu::GameOverMenuPlugin;
 use hud::HUDPlugin;
-use crate::events::GameOver;
+use config::GameConfig;
+use difficulty::Difficulty;
+use resources::Arena;
+use crate::events::{
+    GameOver,
+    PlaySound,
+    CollisionStarted,
+    CollisionEnded,
+    StarCollected,
+    EnemyBounced,
+    EnemySpawned,
+    PlayerHit,
+    PowerUpCollected,
+};
 use crate::AppState;
 
 use systems::*;
 
-pub struct GamePlugin;
+// gameplay runs at a fixed rate so runs are reproducible
+pub const FIXED_TIMESTEP_HZ: f64 = 60.0;
+
+#[derive(Default)]
+pub struct GamePlugin {
+    // runs on the configured arena size with no window, rendering, audio
+    // or menus, e.g. for CI and balancing scripts
+    pub headless: bool,
+}
+
+impl GamePlugin {
+    pub fn headless() -> Self {
+        GamePlugin { headless: true }
+    }
+}
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
+        let config = GameConfig::load();
+
         app
+        .insert_resource(Time::<Fixed>::from_hz(FIXED_TIMESTEP_HZ))
+        .insert_resource(Arena::from(&config.arena))
+        .insert_resource(config)
+        .init_resource::<Difficulty>()
         .add_state::<SimulationState>()
         .add_event::<GameOver>()
+        .add_event::<PlaySound>()
+        .add_event::<CollisionStarted>()
+        .add_event::<CollisionEnded>()
+        .add_event::<StarCollected>()
+        .add_event::<EnemyBounced>()
+        .add_event::<EnemySpawned>()
+        .add_event::<PlayerHit>()
+        .add_event::<PowerUpCollected>()
         .add_plugins((
             EnemyPlugin, 
             PlayerPlugin, 
             ScorePlugin, 
             StarPlugin,
-            PauseMenuPlugin,
-            GameOverMenuPlugin,
-            HUDPlugin,
+            PowerUpPlugin,
+            RngPlugin,
+            ReplayPlugin,
+            PhysicsPlugin,
+            StatsPlugin,
         ))
-        .add_systems(Update,
-        toggle_simulation.run_if(in_state(AppState::Game)))
         .add_systems(OnExit(AppState::Game),
-                    resume_simulation);    
+                    resume_simulation);
+
+        if !self.headless {
+            app
+            .add_plugins((
+                PauseMenuPlugin,
+                GameOverMenuPlugin,
+                HUDPlugin,
+                HighScoresPlugin,
+                ReplayRecorderPlugin,
+                SpritesPlugin,
+                SoundPlugin,
+                ParticlesPlugin,
+            ))
+            .add_systems(Update, (
+                toggle_simulation,
+                pause_on_focus_loss
+            ).run_if(in_state(AppState::Game)));
+        }
     }
 }
 
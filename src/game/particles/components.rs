@@ -0,0 +1,17 @@
+use bevy::prelude::{Component, Timer, TimerMode, Vec2};
+
+// a speck thrown out by a burst, fading until its timer runs out
+#[derive(Component)]
+pub struct Particle {
+    pub velocity: Vec2,
+    pub timer: Timer,
+}
+
+impl Particle {
+    pub fn new(velocity: Vec2, lifetime: f32) -> Self {
+        Particle {
+            velocity,
+            timer: Timer::from_seconds(lifetime, TimerMode::Once),
+        }
+    }
+}
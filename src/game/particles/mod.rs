@@ -0,0 +1,33 @@
+pub mod components;
+mod systems;
+
+use bevy::prelude::*;
+
+use systems::*;
+
+use crate::AppState;
+use crate::game::SimulationState;
+
+pub const PARTICLE_SIZE: f32 = 6.0;
+pub const PARTICLE_LIFETIME: f32 = 0.5;
+// above the gameplay sprites, below the letterbox bars
+pub const PARTICLE_Z: f32 = 10.0;
+
+// bursts where stars and power-ups are picked up, enemies bounce and the
+// player is hit. purely for show, nothing in gameplay reads them
+pub struct ParticlesPlugin;
+
+impl Plugin for ParticlesPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_systems(Update, (
+                spawn_particle_bursts,
+                update_particles
+                    .run_if(in_state(SimulationState::Running))
+            )
+            .run_if(in_state(AppState::Game))
+        )
+        .add_systems(OnExit(AppState::Game),
+            despawn_particles);
+    }
+}
@@ -0,0 +1,88 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::game::particles::{
+    components::*,
+    PARTICLE_SIZE,
+    PARTICLE_LIFETIME,
+    PARTICLE_Z,
+};
+use crate::game::power_up::resources::PowerUpKind;
+use crate::events::{StarCollected, EnemyBounced, PlayerHit, PowerUpCollected};
+
+const STAR_COLOR: Color = Color::rgb(1.0, 0.85, 0.2);
+const BOUNCE_COLOR: Color = Color::rgb(0.8, 0.8, 0.8);
+const HIT_COLOR: Color = Color::rgb(1.0, 0.2, 0.2);
+
+pub fn spawn_particle_bursts(
+    mut commands: Commands,
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut enemy_bounced_event_reader: EventReader<EnemyBounced>,
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut power_up_collected_event_reader: EventReader<PowerUpCollected>
+) {
+    star_collected_event_reader.read().for_each(|event| {
+        burst(&mut commands, event.position, STAR_COLOR, 12, 150.0);
+    });
+    enemy_bounced_event_reader.read().for_each(|event| {
+        burst(&mut commands, event.position, BOUNCE_COLOR, 6, 80.0);
+    });
+    player_hit_event_reader.read().for_each(|event| {
+        // a shield takes the hit in its own color
+        let color = if event.absorbed {
+            PowerUpKind::Shield.color()
+        } else {
+            HIT_COLOR
+        };
+        burst(&mut commands, event.position, color, 20, 250.0);
+    });
+    power_up_collected_event_reader.read().for_each(|event| {
+        burst(&mut commands, event.position, event.kind.color(), 16, 200.0);
+    });
+}
+
+// evenly spread rather than random, so bursts leave the game rng alone
+fn burst(commands: &mut Commands, position: Vec2, color: Color, count: usize, speed: f32) {
+    (0..count).for_each(|index| {
+        let direction = Vec2::from_angle(TAU * index as f32 / count as f32);
+
+        commands.spawn((
+            SpriteBundle {
+                transform: Transform::from_translation(position.extend(PARTICLE_Z)),
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(PARTICLE_SIZE)),
+                    ..default()
+                },
+                ..default()
+            },
+            Particle::new(direction * speed, PARTICLE_LIFETIME)
+        ));
+    });
+}
+
+pub fn update_particles(
+    mut commands: Commands,
+    mut particle_query: Query<(Entity, &mut Transform, &mut Sprite, &mut Particle)>,
+    time: Res<Time>
+) {
+    particle_query.for_each_mut(|(entity, mut transform, mut sprite, mut particle)| {
+        if particle.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            return;
+        }
+
+        transform.translation += (particle.velocity * time.delta_seconds()).extend(0.0);
+        sprite.color.set_a(particle.timer.percent_left());
+    });
+}
+
+pub fn despawn_particles(
+    mut commands: Commands,
+    particle_query: Query<Entity, With<Particle>>
+) {
+    particle_query.for_each(|particle_entity| {
+        commands.entity(particle_entity).despawn();
+    });
+}
@@ -8,4 +8,7 @@ pub struct PauseMenu {}
 pub struct MainMenuButton {}
 
 #[derive(Component)]
-pub struct ResumeButton {}
\ No newline at end of file
+pub struct ResumeButton {}
+
+#[derive(Component)]
+pub struct SettingsButton {}
@@ -1,6 +1,6 @@
 
 mod systems;
-mod components;
+pub mod components;
 
 use bevy::prelude::*;
 
@@ -16,6 +16,7 @@ impl Plugin for PauseMenuPlugin {
                     spawn_pause_menu)
         .add_systems(Update, (
                     interact_with_resume_button,
+                    interact_with_settings_button,
                     interact_with_main_menu_button
                 )
                 .run_if(in_state(SimulationState::Paused))
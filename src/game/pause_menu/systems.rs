@@ -5,18 +5,20 @@ use super::components::*;
 use crate::{
     main_menu::styles::*, 
     game::SimulationState,
+    settings::resources::SettingsMenuOpen,
     AppState
 };
+use crate::loading::resources::GameAssets;
 
 pub fn spawn_pause_menu(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
 ) {
     commands.spawn(
         (NodeBundle {
             style: Style {
                 width: Val::Percent(50.0),
-                height: Val::Percent(50.0),
+                height: Val::Percent(60.0),
                 align_self: AlignSelf::Center,
                 justify_self: JustifySelf::Center,
                 flex_direction: FlexDirection::Column,
@@ -47,7 +49,7 @@ pub fn spawn_pause_menu(
                         sections: vec![
                             TextSection::new(
                                 "Pause Menu",
-                                get_title_text_style(&asset_server)
+                                get_title_text_style(&game_assets)
                             )
                         ],
                         alignment: TextAlignment::Center,
@@ -73,7 +75,31 @@ pub fn spawn_pause_menu(
                     sections: vec![
                         TextSection::new(
                             "Resume", 
-                            get_button_text_style(&asset_server)
+                            get_button_text_style(&game_assets)
+                    )],
+                    alignment: TextAlignment::Center,
+                    ..default()
+                },
+                ..default()
+            });
+        });
+        // === Settings Button ===
+        parent.spawn((
+            ButtonBundle {
+                style: BUTTON_STYLE,
+                background_color: TRANSPERNT_BUTTON_COLOR.into(),
+                ..default()
+            },
+            SettingsButton {}
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+            TextBundle {
+                text: Text {
+                    sections: vec![
+                        TextSection::new(
+                            "Settings", 
+                            get_button_text_style(&game_assets)
                     )],
                     alignment: TextAlignment::Center,
                     ..default()
@@ -97,7 +123,7 @@ pub fn spawn_pause_menu(
                     sections: vec![
                         TextSection::new(
                             "Main Menu", 
-                            get_button_text_style(&asset_server)
+                            get_button_text_style(&game_assets)
                     )],
                     alignment: TextAlignment::Center,
                     ..default()
@@ -135,6 +161,24 @@ pub fn interact_with_resume_button(
             }
 }
 
+pub fn interact_with_settings_button(
+    mut commands: Commands,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), 
+                        (Changed<Interaction>, With<SettingsButton>)>
+) {
+    if let Ok((interaction, mut background_color)) = 
+                button_query.get_single_mut() {
+                    *background_color = match *interaction {
+                    Interaction::Pressed => {
+                        commands.init_resource::<SettingsMenuOpen>();
+                        PRESSED_BUTTON_COLOR.into()
+                    },
+                    Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+                    Interaction::None => TRANSPERNT_BUTTON_COLOR.into(),
+                }
+    }
+}
+
 pub fn interact_with_main_menu_button(
     mut button_query: Query<(&Interaction, &mut BackgroundColor), 
                         (Changed<Interaction>, With<MainMenuButton>)>,
@@ -0,0 +1,120 @@
+
+use std::ops::BitOr;
+
+use bevy::prelude::{Component, Vec2};
+
+// the simulated position, advanced only on fixed ticks. the rendered
+// transform is interpolated between the previous and current tick
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Position {
+    pub current: Vec2,
+    pub previous: Vec2,
+}
+
+impl Position {
+    pub fn new(position: Vec2) -> Self {
+        Position {
+            current: position,
+            previous: position,
+        }
+    }
+
+    // moves without the rendered transform sliding across the arena
+    pub fn teleport(&mut self, position: Vec2) {
+        self.current = position;
+        self.previous = position;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColliderShape {
+    Circle { radius: f32 },
+    // axis aligned box, half its width and height
+    Aabb { half_size: Vec2 },
+}
+
+impl ColliderShape {
+    // the smallest circle around the shape, what the spatial grid goes by
+    pub fn bounding_radius(&self) -> f32 {
+        match self {
+            ColliderShape::Circle { radius } => *radius,
+            ColliderShape::Aabb { half_size } => half_size.length(),
+        }
+    }
+}
+
+// bit flags for what a collider is and what it wants to hear about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollisionLayer(pub u32);
+
+impl CollisionLayer {
+    pub const NONE: CollisionLayer = CollisionLayer(0);
+    pub const PLAYER: CollisionLayer = CollisionLayer(1 << 0);
+    pub const ENEMY: CollisionLayer = CollisionLayer(1 << 1);
+    pub const STAR: CollisionLayer = CollisionLayer(1 << 2);
+    pub const POWER_UP: CollisionLayer = CollisionLayer(1 << 3);
+
+    pub fn intersects(&self, other: CollisionLayer) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for CollisionLayer {
+    type Output = CollisionLayer;
+
+    fn bitor(self, other: CollisionLayer) -> CollisionLayer {
+        CollisionLayer(self.0 | other.0)
+    }
+}
+
+// the shape other entities collide with, centered on the position. a pair
+// is checked when either one's mask includes the other's layer
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Collider {
+    pub shape: ColliderShape,
+    pub layer: CollisionLayer,
+    pub mask: CollisionLayer,
+}
+
+impl Collider {
+    pub fn circle(radius: f32, layer: CollisionLayer, mask: CollisionLayer) -> Self {
+        Collider {
+            shape: ColliderShape::Circle { radius },
+            layer,
+            mask,
+        }
+    }
+
+    pub fn aabb(half_size: Vec2, layer: CollisionLayer, mask: CollisionLayer) -> Self {
+        Collider {
+            shape: ColliderShape::Aabb { half_size },
+            layer,
+            mask,
+        }
+    }
+
+    pub fn interacts_with(&self, other: &Collider) -> bool {
+        self.mask.intersects(other.layer) || other.mask.intersects(self.layer)
+    }
+
+    pub fn overlaps(&self, position: Vec2, other: &Collider, other_position: Vec2) -> bool {
+        match (self.shape, other.shape) {
+            (ColliderShape::Circle { radius }, ColliderShape::Circle { radius: other_radius }) =>
+                position.distance(other_position) < radius + other_radius,
+            (ColliderShape::Circle { radius }, ColliderShape::Aabb { half_size }) =>
+                circle_overlaps_aabb(position, radius, other_position, half_size),
+            (ColliderShape::Aabb { half_size }, ColliderShape::Circle { radius }) =>
+                circle_overlaps_aabb(other_position, radius, position, half_size),
+            (ColliderShape::Aabb { half_size }, ColliderShape::Aabb { half_size: other_half_size }) => {
+                let gap = (position - other_position).abs() - (half_size + other_half_size);
+                gap.x < 0.0 && gap.y < 0.0
+            },
+        }
+    }
+}
+
+fn circle_overlaps_aabb(center: Vec2, radius: f32, box_center: Vec2, half_size: Vec2) -> bool {
+    let closest = center.clamp(box_center - half_size, box_center + half_size);
+
+    center.distance(closest) < radius
+}
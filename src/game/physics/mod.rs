@@ -0,0 +1,58 @@
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+use systems::*;
+use resources::*;
+use crate::AppState;
+use crate::game::SimulationState;
+use crate::game::enemy::EnemySystemSet;
+use crate::game::player::PlayerSystemSet;
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum PhysicsSystemSet {
+    Snapshot,
+    // once everything has moved, before anything checks for collisions
+    BroadPhase,
+    // after enemies push each other apart, before anything reacts to a hit
+    NarrowPhase,
+}
+
+pub struct PhysicsPlugin;
+
+impl Plugin for PhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .init_resource::<SpatialGrid>()
+        .init_resource::<Contacts>()
+        .configure_sets(FixedUpdate, (
+            PhysicsSystemSet::BroadPhase
+                .after(PlayerSystemSet::Confinement)
+                .after(EnemySystemSet::Confinement),
+            PhysicsSystemSet::NarrowPhase
+                .after(PhysicsSystemSet::BroadPhase)
+                .after(EnemySystemSet::Collision)
+                .before(PlayerSystemSet::Collision)
+        ))
+        // runs while paused too so nothing drifts between frames
+        .add_systems(FixedUpdate,
+            snapshot_positions
+                .in_set(PhysicsSystemSet::Snapshot)
+                .run_if(in_state(AppState::Game))
+        )
+        .add_systems(FixedUpdate, (
+                rebuild_spatial_grid.in_set(PhysicsSystemSet::BroadPhase),
+                detect_collisions.in_set(PhysicsSystemSet::NarrowPhase)
+            )
+            .run_if(in_state(AppState::Game))
+            .run_if(in_state(SimulationState::Running))
+        )
+        .add_systems(OnExit(AppState::Game), clear_contacts)
+        .add_systems(Update,
+            interpolate_transforms
+        );
+    }
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+use std::collections::BTreeSet;
+
+use bevy::{prelude::*, utils::HashMap};
+
+// about two enemies across, so most lookups only touch a few cells
+pub const GRID_CELL_SIZE: f32 = 128.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridEntry {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub radius: f32,
+}
+
+// buckets colliders by position so a collision check only looks at the
+// ones nearby instead of every entity. rebuilt from scratch every fixed tick,
+// each entry goes in the cell holding its center
+#[derive(Resource, Debug)]
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<GridEntry>>,
+    largest_radius: f32,
+    len: usize,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        SpatialGrid::new(GRID_CELL_SIZE)
+    }
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self {
+        SpatialGrid {
+            cell_size,
+            cells: HashMap::default(),
+            largest_radius: 0.0,
+            len: 0,
+        }
+    }
+
+    // keeps the cells around, the same ones fill up again next tick
+    pub fn clear(&mut self) {
+        self.cells.values_mut().for_each(Vec::clear);
+        self.largest_radius = 0.0;
+        self.len = 0;
+    }
+
+    pub fn insert(&mut self, entity: Entity, position: Vec2, radius: f32) {
+        self.cells.entry(self.cell(position))
+            .or_default()
+            .push(GridEntry { entity, position, radius });
+        self.largest_radius = self.largest_radius.max(radius);
+        self.len += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // every entry whose circle comes within radius of the point, cell by cell
+    // and in insertion order so the result is the same every run
+    pub fn query(&self, point: Vec2, radius: f32) -> impl Iterator<Item = &GridEntry> + '_ {
+        // an entry can reach in from a neighbouring cell by its own radius
+        let reach = Vec2::splat(radius + self.largest_radius);
+        let min = self.cell(point - reach);
+        let max = self.cell(point + reach);
+
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .filter(move |entry| entry.position.distance(point) < radius + entry.radius)
+    }
+
+    fn cell(&self, position: Vec2) -> IVec2 {
+        (position / self.cell_size).floor().as_ivec2()
+    }
+}
+
+// the pairs that were touching on the last tick, ordered so the
+// collision events come out the same way every run
+#[derive(Resource, Debug, Default)]
+pub struct Contacts {
+    pub pairs: BTreeSet<[Entity; 2]>,
+}
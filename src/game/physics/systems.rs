@@ -0,0 +1,78 @@
+
+use std::collections::BTreeSet;
+
+use bevy::prelude::*;
+
+use crate::game::physics::{components::*, resources::*};
+use crate::events::{CollisionStarted, CollisionEnded};
+
+pub fn snapshot_positions(mut position_query: Query<&mut Position>) {
+    position_query.for_each_mut(|mut position| {
+        position.previous = position.current;
+    });
+}
+
+pub fn interpolate_transforms(
+    mut position_query: Query<(&Position, &mut Transform)>,
+    fixed_time: Res<Time<Fixed>>
+) {
+    let alpha = fixed_time.overstep_percentage();
+
+    position_query.for_each_mut(|(position, mut transform)| {
+        let translation = position.previous.lerp(position.current, alpha);
+
+        transform.translation.x = translation.x;
+        transform.translation.y = translation.y;
+    });
+}
+
+pub fn rebuild_spatial_grid(
+    mut spatial_grid: ResMut<SpatialGrid>,
+    collider_query: Query<(Entity, &Position, &Collider)>
+) {
+    spatial_grid.clear();
+    collider_query.for_each(|(entity, position, collider)| {
+        spatial_grid.insert(entity, position.current, collider.shape.bounding_radius());
+    });
+}
+
+// the narrow phase, checks the grid's candidates against their exact shapes
+// and reports the pairs that started or stopped touching since last tick
+pub fn detect_collisions(
+    collider_query: Query<(Entity, &Position, &Collider)>,
+    spatial_grid: Res<SpatialGrid>,
+    mut contacts: ResMut<Contacts>,
+    mut collision_started_event_writer: EventWriter<CollisionStarted>,
+    mut collision_ended_event_writer: EventWriter<CollisionEnded>
+) {
+    let mut touching = BTreeSet::new();
+
+    collider_query.for_each(|(entity, position, collider)| {
+        spatial_grid.query(position.current, collider.shape.bounding_radius())
+            // every pair once, the lower entity first
+            .filter(|entry| entry.entity > entity)
+            .filter_map(|entry| collider_query.get(entry.entity).ok())
+            .filter(|(_, other_position, other_collider)| {
+                collider.interacts_with(other_collider) &&
+                    collider.overlaps(position.current, other_collider,
+                                    other_position.current)
+            })
+            .for_each(|(other_entity, _, _)| {
+                touching.insert([entity, other_entity]);
+            });
+    });
+
+    touching.difference(&contacts.pairs).for_each(|entities| {
+        collision_started_event_writer.send(CollisionStarted { entities: *entities });
+    });
+    contacts.pairs.difference(&touching).for_each(|entities| {
+        collision_ended_event_writer.send(CollisionEnded { entities: *entities });
+    });
+
+    contacts.pairs = touching;
+}
+
+// a new run does not hear about the last one's contacts ending
+pub fn clear_contacts(mut contacts: ResMut<Contacts>) {
+    contacts.pairs.clear();
+}
@@ -1,5 +1,19 @@
 
-use bevy::prelude::Component;
+use bevy::prelude::{Component, Timer, TimerMode};
 
 #[derive(Component)]
 pub struct Player {}
+
+// enemies pass through the player until the timer runs out
+#[derive(Component)]
+pub struct Invulnerable {
+    pub timer: Timer,
+}
+
+impl Invulnerable {
+    pub fn new(seconds: f32) -> Self {
+        Invulnerable {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+}
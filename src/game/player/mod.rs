@@ -2,19 +2,26 @@
 use bevy::prelude::*;
 
 pub mod components;
+pub mod resources;
 pub mod systems;
 
 use systems::*;
+use resources::*;
 use crate::AppState;
 use crate::game::SimulationState;
+use crate::game::enemy::EnemySystemSet;
+use crate::game::physics::PhysicsSystemSet;
 
-pub const PLAYER_SPEED: f32 = 500.0;
 pub const PLAYER_SIZE: f32 = 64.0; // this is the player sprite size
+// stick travel ignored around the center so a worn stick does not drift
+pub const STICK_DEADZONE: f32 = 0.2;
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub enum PlayerSystemSet {
+    Input,
     Movment,
     Confinement,
+    Collision,
     PlayerSpawn
 }
 
@@ -23,23 +30,35 @@ pub struct PlayerPlugin;
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app
-        .configure_sets(Update, 
-            PlayerSystemSet::Movment
-                    .before(PlayerSystemSet::Confinement))
+        .init_resource::<PlayerInput>()
+        .configure_sets(FixedUpdate, (
+            PlayerSystemSet::Input
+                .after(PhysicsSystemSet::Snapshot),
+            PlayerSystemSet::Movment,
+            PlayerSystemSet::Confinement,
+            PlayerSystemSet::Collision
+                .after(EnemySystemSet::Collision)
+        ).chain())
         .configure_sets(OnEnter(AppState::Game), 
                         PlayerSystemSet::PlayerSpawn)
-        .add_systems(OnEnter(AppState::Game),
-                spawn_player.in_set(PlayerSystemSet::PlayerSpawn))
-        .add_systems(Update,(
+        .add_systems(OnEnter(AppState::Game), (
+                spawn_player.in_set(PlayerSystemSet::PlayerSpawn),
+                insert_lives
+        ))
+        .add_systems(FixedUpdate,(
+                read_player_input.in_set(PlayerSystemSet::Input),
                 player_movement.in_set(PlayerSystemSet::Movment), 
                 confine_player_movement.in_set(PlayerSystemSet::Confinement),
-                enemy_hit_player,
-                player_hit_star
+                (tick_invulnerability, player_hit_star, enemy_hit_player)
+                    .chain()
+                    .in_set(PlayerSystemSet::Collision)
             )
             .run_if(in_state(AppState::Game))
             .run_if(in_state(SimulationState::Running))
         )
-        .add_systems(OnExit(AppState::Game), 
-                despawn_player);
+        .add_systems(OnExit(AppState::Game), (
+                despawn_player,
+                remove_lives
+        ));
     }
 }
@@ -0,0 +1,15 @@
+
+use bevy::prelude::{Resource, Vec2};
+
+// what is left of the run, it ends when the last life is lost
+#[derive(Resource, Debug)]
+pub struct Lives {
+    pub remaining: u32,
+}
+
+// the movement requested for the current fixed tick, filled from the
+// keyboard, a gamepad or a replay. its length scales the player's speed
+#[derive(Resource, Debug, Default)]
+pub struct PlayerInput {
+    pub direction: Vec2,
+}
\ No newline at end of file
@@ -1,38 +1,51 @@
 
-use bevy::{prelude::*, window::PrimaryWindow};
+use bevy::prelude::*;
 
 use crate::game::player::{
     components::*,
+    resources::*,
     PLAYER_SIZE,
-    PLAYER_SPEED,
+    STICK_DEADZONE,
 };
+use crate::game::config::GameConfig;
+use crate::game::physics::{
+    components::{Position, Collider, CollisionLayer},
+    resources::Contacts
+};
+use crate::game::resources::Arena;
 use crate::game::enemy::{
     components::Enemy, 
-    ENEMY_SIZE,
     confine_actor_axis
 };
-use crate::game::score::resources::Score;
-use crate::game::star::{components::Star, STAR_SIZE};
-use crate::events::GameOver;
+use crate::game::score::resources::{Score, Combo};
+use crate::game::star::components::Star;
+use crate::game::power_up::resources::ActivePowerUps;
+use crate::events::{GameOver, CollisionStarted, StarCollected, PlayerHit, other_in_pair};
+use crate::controls::resources::InputAction;
 
 pub fn spawn_player(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>
+    arena: Res<Arena>
 ) {
-    let window = window_query.get_single().unwrap();
-
     commands.spawn((
-        SpriteBundle {
-            transform: Transform::from_xyz(window.width() / 2.0, 
-                                        window.height() / 2.0, 0.0),
-            texture: asset_server.load("sprites/ball_blue_large.png"),
-            ..default()
-        },
+        Position::new(arena.center()),
         Player {},
+        Collider::circle(PLAYER_SIZE / 2.0, CollisionLayer::PLAYER,
+            CollisionLayer::ENEMY | CollisionLayer::STAR | CollisionLayer::POWER_UP),
     ));
 }
 
+pub fn insert_lives(
+    mut commands: Commands,
+    config: Res<GameConfig>
+) {
+    commands.insert_resource(Lives { remaining: config.player.lives });
+}
+
+pub fn remove_lives(mut commands: Commands) {
+    commands.remove_resource::<Lives>();
+}
+
 pub fn despawn_player(
     mut commands: Commands,
     player_query: Query<Entity, With<Player>>
@@ -43,114 +56,230 @@ pub fn despawn_player(
 }
 
 
-pub fn player_movement(
-    keyboard_input: Res<Input<KeyCode>>,
-    mut player_query: Query<&mut Transform, With<Player>>,
-    time: Res<Time>
+pub fn read_player_input(
+    action_input: Res<Input<InputAction>>,
+    gamepads: Res<Gamepads>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    mut player_input: ResMut<PlayerInput>
 ) {
-    if let Ok(mut transform) = player_query.get_single_mut() {
-        let mut direction = Vec3::ZERO;
+    let mut direction = Vec2::ZERO;
 
-        if keyboard_input.pressed(KeyCode::Left) || 
-            keyboard_input.pressed(KeyCode::A) {
-                direction.x -= 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::Right) || 
-            keyboard_input.pressed(KeyCode::D) {
-                direction.x += 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::Up) || 
-            keyboard_input.pressed(KeyCode::W) {
-                direction.y += 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::Down) || 
-            keyboard_input.pressed(KeyCode::S) {
-                direction.y -= 1.0;
-        }
+    if action_input.pressed(InputAction::MoveLeft) {
+        direction.x -= 1.0;
+    }
+    if action_input.pressed(InputAction::MoveRight) {
+        direction.x += 1.0;
+    }
+    if action_input.pressed(InputAction::MoveUp) {
+        direction.y += 1.0;
+    }
+    if action_input.pressed(InputAction::MoveDown) {
+        direction.y -= 1.0;
+    }
 
-        if direction.length() > 0.0 {
-            direction = direction.normalize();
-        }
+    // the first stick that is being used takes over from the buttons
+    if let Some(stick_direction) = gamepads.iter()
+        .map(|gamepad| read_stick_direction(gamepad, &gamepad_axes))
+        .find(|stick_direction| *stick_direction != Vec2::ZERO) {
+            direction = stick_direction;
+    }
+
+    player_input.direction = direction;
+}
+
+// the left stick moves proportionally to how far it is tilted
+fn read_stick_direction(
+    gamepad: Gamepad,
+    gamepad_axes: &Axis<GamepadAxis>
+) -> Vec2 {
+    let axis = |axis_type| gamepad_axes
+        .get(GamepadAxis::new(gamepad, axis_type))
+        .unwrap_or(0.0);
+    let stick = Vec2::new(axis(GamepadAxisType::LeftStickX),
+                        axis(GamepadAxisType::LeftStickY));
+    let length = stick.length();
+
+    if length <= STICK_DEADZONE {
+        return Vec2::ZERO;
+    }
+
+    // rescaled so speed ramps up from zero at the edge of the deadzone
+    let scale = ((length - STICK_DEADZONE) / (1.0 - STICK_DEADZONE)).min(1.0);
+    stick / length * scale
+}
+
+pub fn player_movement(
+    player_input: Res<PlayerInput>,
+    mut player_query: Query<&mut Position, With<Player>>,
+    time: Res<Time>,
+    config: Res<GameConfig>
+) {
+    if let Ok(mut position) = player_query.get_single_mut() {
+        // diagonals are no faster, a half tilted stick is half as fast
+        let direction = player_input.direction.clamp_length_max(1.0);
 
-        transform.translation += direction * PLAYER_SPEED * time.delta_seconds();
+        position.current += direction * config.player.speed * time.delta_seconds();
 
     }
 }
 
 pub fn confine_player_movement(
-    mut player_query: Query<&mut Transform, With<Player>>,
-    window_query: Query<&Window, With<PrimaryWindow>>
+    mut player_query: Query<&mut Position, With<Player>>,
+    arena: Res<Arena>
 ) {
-    if let Ok(mut player_transform) = player_query.get_single_mut() {
-        let window = window_query.get_single().unwrap();
-        let mut translation = player_transform.translation;
+    if let Ok(mut player_position) = player_query.get_single_mut() {
+        let mut position = player_position.current;
         
-        confine_actor_axis(&mut translation.x, window.width(), PLAYER_SIZE);
-        confine_actor_axis(&mut translation.y, window.height(), PLAYER_SIZE);
+        confine_actor_axis(&mut position.x, arena.width(), PLAYER_SIZE);
+        confine_actor_axis(&mut position.y, arena.height(), PLAYER_SIZE);
 
-        player_transform.translation = translation;
+        player_position.current = position;
 
     }
 }
 
+pub fn tick_invulnerability(
+    mut commands: Commands,
+    mut player_query: Query<(Entity, &mut Invulnerable), With<Player>>,
+    enemy_query: Query<(), With<Enemy>>,
+    mut contacts: ResMut<Contacts>,
+    time: Res<Time>
+) {
+    if let Ok((player_entity, mut invulnerable)) = player_query.get_single_mut() {
+        if invulnerable.timer.tick(time.delta()).finished() {
+            commands.entity(player_entity).remove::<Invulnerable>();
+
+            // enemies still on the player are forgotten, so the next narrow
+            // phase reports them as new contacts and they land a hit
+            contacts.pairs.retain(|pair| !other_in_pair(*pair, player_entity)
+                .is_some_and(|other_entity| enemy_query.contains(other_entity)));
+        }
+    }
+}
+
 pub fn enemy_hit_player(
     mut commands: Commands,
-    mut player_query: Query<(Entity, &Transform), With<Player>>,
-    enemy_query: Query<&Transform, With<Enemy>>,
-    asset_server: Res<AssetServer>,
+    mut collision_started_event_reader: EventReader<CollisionStarted>,
+    mut player_query: Query<(Entity, &mut Position), (With<Player>, Without<Invulnerable>)>,
+    enemy_query: Query<&Position, (With<Enemy>, Without<Player>)>,
+    mut player_hit_event_writer: EventWriter<PlayerHit>,
     mut game_over_event_writer: EventWriter<GameOver>,
-    score: Res<Score>
+    mut lives: ResMut<Lives>,
+    mut active_power_ups: ResMut<ActivePowerUps>,
+    score: Res<Score>,
+    combo: Res<Combo>,
+    arena: Res<Arena>,
+    config: Res<GameConfig>
 ) {
-    if let Ok((player_entity, player_transform)) = player_query.get_single_mut() {
-        for enemy_transform in enemy_query.iter() {
-            let distance = player_transform.translation
-                                .distance(enemy_transform.translation);
-            let player_radius = PLAYER_SIZE / 2.0;
-            let enemy_radius = ENEMY_SIZE / 2.0;
-
-            if distance < (player_radius + enemy_radius) {
-                println!("Enemy hit player! Game Over");
-
-                let sound_effect = asset_server.load("audio/explosionCrunch_000.ogg");
-                commands.spawn(AudioBundle {
-                    source: sound_effect,
-                    settings: PlaybackSettings::DESPAWN,
-                });
-                commands.entity(player_entity).despawn();
-                game_over_event_writer.send(GameOver { score: score.value });
-                break;
-            }
-        }
+    // enemies touching the player while it blinks are let go, one still
+    // touching it once the blinking ends comes round again as a new contact
+    let Ok((player_entity, mut player_position)) = player_query.get_single_mut() else {
+        collision_started_event_reader.clear();
+        return;
+    };
+
+    // read to the end, the first enemy is the one that lands the hit
+    let enemy_entities: Vec<Entity> = collision_started_event_reader.read()
+        .filter_map(|collision| collision.other(player_entity))
+        .filter(|other_entity| enemy_query.contains(*other_entity))
+        .collect();
+    let Some(&enemy_entity) = enemy_entities.first() else {
+        return;
+    };
+
+    let absorbed = active_power_ups.absorb_hit();
+    player_hit_event_writer.send(PlayerHit {
+        player: player_entity,
+        enemy: enemy_entity,
+        position: player_position.current,
+        absorbed,
+    });
+
+    // the player stays put and gets the usual moment to get clear
+    if absorbed {
+        println!("Enemy hit player! The shield absorbed it");
+        commands.entity(player_entity)
+            .insert(Invulnerable::new(config.player.invulnerable_time));
+        return;
     }
+
+    lives.remaining = lives.remaining.saturating_sub(1);
+
+    if lives.remaining == 0 {
+        println!("Enemy hit player! Game Over");
+        commands.entity(player_entity).despawn();
+        game_over_event_writer.send(GameOver {
+            score: score.value,
+            best_combo: combo.best,
+        });
+        return;
+    }
+
+    println!("Enemy hit player! {} lives left", lives.remaining);
+    let enemy_positions: Vec<Vec2> = enemy_query.iter()
+        .map(|enemy_position| enemy_position.current)
+        .collect();
+    player_position.teleport(safe_spawn_point(&arena, &enemy_positions));
+    commands.entity(player_entity)
+        .insert(Invulnerable::new(config.player.invulnerable_time));
+}
+
+// the spot on a coarse grid that is furthest from every enemy,
+// no randomness so replays respawn the player in the same place
+pub fn safe_spawn_point(arena: &Arena, enemy_positions: &[Vec2]) -> Vec2 {
+    const COLUMNS: usize = 8;
+    const ROWS: usize = 5;
+
+    let margin = Vec2::splat(PLAYER_SIZE);
+    let area = (arena.size - margin * 2.0).max(Vec2::ZERO);
+    let candidates = (0..ROWS).flat_map(|row| (0..COLUMNS).map(move |column| {
+        let fraction = Vec2::new(column as f32 / (COLUMNS - 1) as f32,
+                                row as f32 / (ROWS - 1) as f32);
+        margin + area * fraction
+    }));
+
+    candidates
+        .map(|candidate| {
+            let clearance = enemy_positions.iter()
+                .map(|enemy_position| candidate.distance(*enemy_position))
+                .fold(f32::INFINITY, f32::min);
+            (candidate, clearance)
+        })
+        // the first of equally safe spots, so the choice is stable
+        .fold(None, |best: Option<(Vec2, f32)>, (candidate, clearance)| {
+            match best {
+                Some((_, best_clearance)) if best_clearance >= clearance => best,
+                _ => Some((candidate, clearance)),
+            }
+        })
+        .map(|(candidate, _)| candidate)
+        .unwrap_or(arena.center())
 }
 
 pub fn player_hit_star(
     mut commands: Commands,
-    player_query: Query<&Transform, With<Player>>,
-    mut star_query: Query<(Entity, &Transform), With<Star>>,
-    asset_server: Res<AssetServer>,
-    mut score: ResMut<Score>
+    mut collision_started_event_reader: EventReader<CollisionStarted>,
+    player_query: Query<Entity, With<Player>>,
+    star_query: Query<&Position, With<Star>>,
+    mut star_collected_event_writer: EventWriter<StarCollected>
 ) {
-    if let Ok(player_transform) = player_query.get_single() {
-        star_query
-            .for_each_mut(|(star_entety, star_transform)| {
-            let distance = player_transform.translation
-                                .distance(star_transform.translation);
-            let player_radius = PLAYER_SIZE / 2.0;
-            let star_radius = STAR_SIZE / 2.0;
-
-            if distance < (player_radius + star_radius) {
-                println!("Player hit star! Star collected");
-
-                let sound_effect = asset_server
-                                .load("audio/laserLarge_000.ogg");
-                commands.spawn(AudioBundle {
-                    source: sound_effect,
-                    settings: PlaybackSettings::DESPAWN,
-                });
-                commands.entity(star_entety).despawn();
-                score.value += 1;
-            }
+    let Ok(player_entity) = player_query.get_single() else {
+        collision_started_event_reader.clear();
+        return;
+    };
+
+    collision_started_event_reader.read()
+        .filter_map(|collision| collision.other(player_entity))
+        .for_each(|star_entity| {
+            let Ok(star_position) = star_query.get(star_entity) else {
+                return;
+            };
+            println!("Player hit star! Star collected");
+
+            star_collected_event_writer.send(StarCollected {
+                star: star_entity,
+                position: star_position.current,
+            });
+            commands.entity(star_entity).despawn();
         });
-    }
 }
@@ -0,0 +1,9 @@
+use bevy::prelude::Component;
+
+use super::resources::PowerUpKind;
+
+// waiting in the arena to be picked up
+#[derive(Component)]
+pub struct PowerUp {
+    pub kind: PowerUpKind,
+}
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+use systems::*;
+use crate::AppState;
+use crate::game::SimulationState;
+use crate::game::enemy::EnemySystemSet;
+use crate::game::physics::PhysicsSystemSet;
+use crate::game::player::{
+    PlayerSystemSet,
+    systems::{player_hit_star, enemy_hit_player}
+};
+
+pub const POWER_UP_SIZE: f32 = 40.0; // drawn with the star sprite scaled up
+
+pub struct PowerUpPlugin;
+
+impl Plugin for PowerUpPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_systems(OnEnter(AppState::Game),
+            insert_power_up_resources)
+        .add_systems(FixedUpdate, (
+                    // effects run out before anything moves, so slow motion
+                    // covers the same ticks in a replay
+                    tick_active_power_ups
+                        .after(PhysicsSystemSet::Snapshot)
+                        .before(EnemySystemSet::Movment),
+                    attract_stars
+                        .after(PhysicsSystemSet::BroadPhase)
+                        .before(PhysicsSystemSet::NarrowPhase),
+                    // a shield picked up on the tick of a hit still absorbs it,
+                    // and enemies a bomb clears are gone before they can land one
+                    (player_hit_power_up, apply_deferred)
+                        .chain()
+                        .in_set(PlayerSystemSet::Collision)
+                        .after(player_hit_star)
+                        .before(enemy_hit_player),
+                    (tick_power_up_spawn_timer, spawn_power_ups_over_time)
+                        .chain()
+                        .after(PlayerSystemSet::Collision)
+                )
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::Running))
+        )
+        .add_systems(OnExit(AppState::Game), (
+            despawn_power_ups,
+            remove_power_up_resources
+        ));
+    }
+}
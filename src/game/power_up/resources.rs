@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+use bevy::prelude::{Color, Resource, Timer, TimerMode};
+
+use crate::game::config::PowerUpConfig;
+
+// how long the HUD shows a bomb going off, it has no lasting effect
+pub const BOMB_FLASH_TIME: f32 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerUpKind {
+    Shield,
+    SlowMotion,
+    Magnet,
+    Bomb,
+}
+
+impl PowerUpKind {
+    pub const ALL: [PowerUpKind; 4] = [
+        PowerUpKind::Shield,
+        PowerUpKind::SlowMotion,
+        PowerUpKind::Magnet,
+        PowerUpKind::Bomb,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PowerUpKind::Shield => "Shield",
+            PowerUpKind::SlowMotion => "Slow",
+            PowerUpKind::Magnet => "Magnet",
+            PowerUpKind::Bomb => "Bomb",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            PowerUpKind::Shield => Color::rgb(0.3, 0.8, 1.0),
+            PowerUpKind::SlowMotion => Color::rgb(0.6, 1.0, 0.4),
+            PowerUpKind::Magnet => Color::rgb(1.0, 0.4, 0.9),
+            PowerUpKind::Bomb => Color::rgb(1.0, 0.5, 0.2),
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct PowerUpSpawnTimer {
+    pub timer: Timer,
+}
+
+impl PowerUpSpawnTimer {
+    pub fn new(spawn_time: f32) -> Self {
+        PowerUpSpawnTimer {
+            timer: Timer::from_seconds(spawn_time,
+                                 TimerMode::Repeating),
+        }
+    }
+}
+
+// the effects picked up so far that have not run out yet
+#[derive(Resource, Debug, Default)]
+pub struct ActivePowerUps {
+    pub shield: Option<Timer>,
+    pub shield_charges: u32,
+    pub slow_motion: Option<Timer>,
+    pub magnet: Option<Timer>,
+    pub bomb: Option<Timer>,
+}
+
+impl ActivePowerUps {
+    // a second pickup of a running effect restarts its timer,
+    // only the shield also stacks up charges
+    pub fn activate(&mut self, kind: PowerUpKind, config: &PowerUpConfig) {
+        let timer = |seconds| Some(Timer::from_seconds(seconds, TimerMode::Once));
+
+        match kind {
+            PowerUpKind::Shield => {
+                self.shield = timer(config.shield.duration);
+                self.shield_charges = (self.shield_charges + 1)
+                    .min(config.shield.max_charges);
+            },
+            PowerUpKind::SlowMotion => self.slow_motion = timer(config.slow_motion.duration),
+            PowerUpKind::Magnet => self.magnet = timer(config.magnet.duration),
+            PowerUpKind::Bomb => self.bomb = timer(BOMB_FLASH_TIME),
+        }
+    }
+
+    pub fn tick(&mut self, delta: Duration) {
+        [&mut self.shield, &mut self.slow_motion, &mut self.magnet, &mut self.bomb]
+            .into_iter()
+            .for_each(|effect| {
+                let expired = effect.as_mut()
+                    .is_some_and(|timer| timer.tick(delta).finished());
+                if expired {
+                    *effect = None;
+                }
+            });
+
+        if self.shield.is_none() {
+            self.shield_charges = 0;
+        }
+    }
+
+    // uses up a shield charge if there is one
+    pub fn absorb_hit(&mut self) -> bool {
+        if self.shield_charges == 0 {
+            return false;
+        }
+
+        self.shield_charges -= 1;
+        if self.shield_charges == 0 {
+            self.shield = None;
+        }
+        true
+    }
+
+    pub fn timer(&self, kind: PowerUpKind) -> Option<&Timer> {
+        match kind {
+            PowerUpKind::Shield => self.shield.as_ref(),
+            PowerUpKind::SlowMotion => self.slow_motion.as_ref(),
+            PowerUpKind::Magnet => self.magnet.as_ref(),
+            PowerUpKind::Bomb => self.bomb.as_ref(),
+        }
+    }
+
+    pub fn is_active(&self, kind: PowerUpKind) -> bool {
+        self.timer(kind).is_some()
+    }
+
+    pub fn enemy_speed_scale(&self, config: &PowerUpConfig) -> f32 {
+        if self.is_active(PowerUpKind::SlowMotion) {
+            config.slow_motion.enemy_speed
+        } else {
+            1.0
+        }
+    }
+}
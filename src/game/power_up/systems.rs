@@ -0,0 +1,184 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::game::power_up::{
+    components::*,
+    resources::*,
+    POWER_UP_SIZE
+};
+use crate::game::enemy::{components::Enemy, confine_actor_axis};
+use crate::game::player::components::Player;
+use crate::game::star::components::Star;
+use crate::game::config::GameConfig;
+use crate::game::rng::resources::{GameRng, RngStream};
+use crate::game::physics::{
+    components::{Position, Collider, CollisionLayer},
+    resources::SpatialGrid
+};
+use crate::game::resources::Arena;
+use crate::events::{CollisionStarted, PowerUpCollected};
+
+pub fn insert_power_up_resources(
+    mut commands: Commands,
+    config: Res<GameConfig>
+) {
+    commands.insert_resource(PowerUpSpawnTimer::new(config.power_up.spawn_time));
+    commands.init_resource::<ActivePowerUps>();
+}
+
+pub fn remove_power_up_resources(mut commands: Commands) {
+    commands.remove_resource::<PowerUpSpawnTimer>();
+    commands.remove_resource::<ActivePowerUps>();
+}
+
+pub fn despawn_power_ups(
+    mut commands: Commands,
+    power_up_query: Query<Entity, With<PowerUp>>
+) {
+    power_up_query.iter().for_each(|power_up_entity| {
+        commands.entity(power_up_entity).despawn();
+    });
+}
+
+pub fn tick_active_power_ups(
+    mut active_power_ups: ResMut<ActivePowerUps>,
+    time: Res<Time>
+) {
+    active_power_ups.tick(time.delta());
+}
+
+pub fn attract_stars(
+    player_query: Query<&Position, With<Player>>,
+    mut star_query: Query<&mut Position, (With<Star>, Without<Player>)>,
+    spatial_grid: Res<SpatialGrid>,
+    active_power_ups: Res<ActivePowerUps>,
+    config: Res<GameConfig>,
+    time: Res<Time>
+) {
+    if !active_power_ups.is_active(PowerUpKind::Magnet) {
+        return;
+    }
+    let Ok(player_position) = player_query.get_single() else {
+        return;
+    };
+
+    let magnet = &config.power_up.magnet;
+    let step = magnet.speed * time.delta_seconds();
+
+    spatial_grid.query(player_position.current, magnet.radius).for_each(|entry| {
+        let Ok(mut star_position) = star_query.get_mut(entry.entity) else {
+            return;
+        };
+        let offset = player_position.current - star_position.current;
+        let distance = offset.length();
+
+        if distance <= magnet.radius && distance > 0.0 {
+            star_position.current += offset / distance * step.min(distance);
+        }
+    });
+}
+
+pub fn player_hit_power_up(
+    mut commands: Commands,
+    mut collision_started_event_reader: EventReader<CollisionStarted>,
+    player_query: Query<(Entity, &Position), With<Player>>,
+    power_up_query: Query<(&PowerUp, &Position)>,
+    enemy_query: Query<(Entity, &Position), With<Enemy>>,
+    mut power_up_collected_event_writer: EventWriter<PowerUpCollected>,
+    mut active_power_ups: ResMut<ActivePowerUps>,
+    config: Res<GameConfig>
+) {
+    let Ok((player_entity, player_position)) = player_query.get_single() else {
+        collision_started_event_reader.clear();
+        return;
+    };
+
+    collision_started_event_reader.read()
+        .filter_map(|collision| collision.other(player_entity))
+        .for_each(|power_up_entity| {
+            let Ok((power_up, power_up_position)) = power_up_query.get(power_up_entity) else {
+                return;
+            };
+            println!("Player picked up {}!", power_up.kind.label());
+
+            power_up_collected_event_writer.send(PowerUpCollected {
+                power_up: power_up_entity,
+                kind: power_up.kind,
+                position: power_up_position.current,
+            });
+            commands.entity(power_up_entity).despawn();
+            active_power_ups.activate(power_up.kind, &config.power_up);
+
+            if power_up.kind == PowerUpKind::Bomb {
+                nearest_enemies(player_position.current, &enemy_query,
+                                config.power_up.bomb.count)
+                    .into_iter()
+                    .for_each(|enemy_entity| {
+                        commands.entity(enemy_entity).despawn();
+                    });
+            }
+        });
+}
+
+// closest first, enemies at the same distance keep their query order
+fn nearest_enemies(
+    origin: Vec2,
+    enemy_query: &Query<(Entity, &Position), With<Enemy>>,
+    count: usize
+) -> Vec<Entity> {
+    let mut enemies: Vec<(Entity, f32)> = enemy_query.iter()
+        .map(|(enemy_entity, enemy_position)| {
+            (enemy_entity, origin.distance(enemy_position.current))
+        })
+        .collect();
+    enemies.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    enemies.into_iter()
+        .take(count)
+        .map(|(enemy_entity, _)| enemy_entity)
+        .collect()
+}
+
+pub fn tick_power_up_spawn_timer(
+    mut power_up_spawn_timer: ResMut<PowerUpSpawnTimer>,
+    time: Res<Time>
+) {
+    power_up_spawn_timer.timer.tick(time.delta());
+}
+
+pub fn spawn_power_ups_over_time(
+    mut commands: Commands,
+    arena: Res<Arena>,
+    power_up_spawn_timer: Res<PowerUpSpawnTimer>,
+    power_up_query: Query<With<PowerUp>>,
+    config: Res<GameConfig>,
+    mut game_rng: ResMut<GameRng>
+) {
+    if power_up_spawn_timer.timer.finished()
+        && power_up_query.iter().count() < config.power_up.max_count {
+        let rng = game_rng.stream(RngStream::PowerUp);
+        power_up_spawn(&arena, &mut commands, rng);
+    }
+}
+
+fn power_up_spawn(
+    arena: &Arena,
+    commands: &mut Commands,
+    rng: &mut impl Rng
+) {
+    let arena_width = arena.width();
+    let arena_height = arena.height();
+    let kind = PowerUpKind::ALL[rng.gen_range(0..PowerUpKind::ALL.len())];
+    let mut random_x = rng.gen::<f32>() * arena_width;
+    let mut random_y = rng.gen::<f32>() * arena_height;
+
+    confine_actor_axis(&mut random_x, arena_width, POWER_UP_SIZE);
+    confine_actor_axis(&mut random_y, arena_height, POWER_UP_SIZE);
+
+    commands.spawn((
+        Position::new(Vec2::new(random_x, random_y)),
+        PowerUp { kind },
+        Collider::circle(POWER_UP_SIZE / 2.0, CollisionLayer::POWER_UP,
+                        CollisionLayer::NONE)
+    ));
+}
@@ -0,0 +1,56 @@
+
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use systems::*;
+use resources::*;
+use crate::AppState;
+use crate::game::SimulationState;
+
+use super::player::PlayerSystemSet;
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_systems(OnEnter(AppState::Game),
+            reset_replay.run_if(resource_exists::<ReplayPlayback>()))
+        .add_systems(FixedUpdate,
+            // overrides whatever the keyboard said this tick
+            play_back_player_input
+                .run_if(resource_exists::<ReplayPlayback>())
+                .after(PlayerSystemSet::Input)
+                .before(PlayerSystemSet::Movment)
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::Running))
+        )
+        .add_systems(Update,
+            check_replay_result.run_if(resource_exists::<ReplayPlayback>())
+        );
+    }
+}
+
+// saves every played run, headless runs have no input worth keeping
+pub struct ReplayRecorderPlugin;
+
+impl Plugin for ReplayRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_systems(OnEnter(AppState::Game),
+            start_recording.run_if(not(resource_exists::<ReplayPlayback>())))
+        .add_systems(FixedUpdate,
+            record_player_input
+                .run_if(resource_exists::<ReplayRecorder>())
+                .after(PlayerSystemSet::Input)
+                .before(PlayerSystemSet::Movment)
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::Running))
+        )
+        .add_systems(Update,
+            save_replay
+        );
+    }
+}
@@ -0,0 +1,232 @@
+
+use std::{fmt, fs, path::Path};
+
+use bevy::prelude::{Resource, Vec2};
+
+use crate::game::difficulty::Difficulty;
+use crate::storage::{self, StorageError};
+
+pub const REPLAY_MAGIC: &[u8; 4] = b"BGRP";
+// version 2 stores analog movement, version 1 only had key presses
+pub const REPLAY_VERSION: u8 = 2;
+pub const REPLAY_EXTENSION: &str = "replay";
+
+// version 1 input masks
+const INPUT_UP: u8 = 1 << 0;
+const INPUT_DOWN: u8 = 1 << 1;
+const INPUT_LEFT: u8 = 1 << 2;
+const INPUT_RIGHT: u8 = 1 << 3;
+
+// each movement axis is stored as a signed byte
+const INPUT_AXIS_STEPS: f32 = i8::MAX as f32;
+
+// magic, version, tick rate, seed, difficulty, arena,
+// final score, tick count and run count
+const HEADER_SIZE: usize = 4 + 1 + 2 + 8 + 1 + 4 + 4 + 4 + 4 + 4;
+// input and run length
+const RUN_SIZE: usize = 2 + 2;
+const RUN_SIZE_V1: usize = 1 + 2;
+
+// the movement of one fixed tick, see `encode_input`
+pub type ReplayInput = [i8; 2];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replay {
+    pub tick_hz: u16,
+    pub seed: u64,
+    pub difficulty: Difficulty,
+    pub arena: Vec2,
+    pub final_score: u32,
+    // one input per fixed tick
+    pub inputs: Vec<ReplayInput>,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    Read(StorageError),
+    NotAReplay,
+    UnsupportedVersion(u8),
+    Corrupt(&'static str),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Read(error) => write!(f, "{}", error),
+            ReplayError::NotAReplay => write!(f, "not a replay file"),
+            ReplayError::UnsupportedVersion(version) =>
+                write!(f, "unsupported replay version {}", version),
+            ReplayError::Corrupt(reason) => write!(f, "corrupt replay: {}", reason),
+        }
+    }
+}
+
+impl Replay {
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        let bytes = fs::read(path)
+            .map_err(|error| ReplayError::Read(error.into()))?;
+
+        Replay::decode(&bytes)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), StorageError> {
+        storage::save_bytes(path, &self.encode())
+    }
+
+    // inputs are stored run length encoded, held keys compress well
+    pub fn encode(&self) -> Vec<u8> {
+        let runs = encode_runs(&self.inputs);
+        let mut bytes = Vec::with_capacity(HEADER_SIZE + runs.len() * RUN_SIZE);
+
+        bytes.extend_from_slice(REPLAY_MAGIC);
+        bytes.push(REPLAY_VERSION);
+        bytes.extend_from_slice(&self.tick_hz.to_le_bytes());
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+        bytes.push(self.difficulty.index());
+        bytes.extend_from_slice(&self.arena.x.to_le_bytes());
+        bytes.extend_from_slice(&self.arena.y.to_le_bytes());
+        bytes.extend_from_slice(&self.final_score.to_le_bytes());
+        bytes.extend_from_slice(&(self.inputs.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(runs.len() as u32).to_le_bytes());
+
+        runs.iter().for_each(|(input, length)| {
+            bytes.extend(input.map(|axis| axis as u8));
+            bytes.extend_from_slice(&length.to_le_bytes());
+        });
+
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, ReplayError> {
+        if !bytes.starts_with(REPLAY_MAGIC) {
+            return Err(ReplayError::NotAReplay);
+        }
+
+        let mut reader = ByteReader { bytes, position: REPLAY_MAGIC.len() };
+        let version = reader.read::<1>()?[0];
+        let run_size = match version {
+            1 => RUN_SIZE_V1,
+            REPLAY_VERSION => RUN_SIZE,
+            _ => return Err(ReplayError::UnsupportedVersion(version)),
+        };
+
+        let tick_hz = u16::from_le_bytes(reader.read()?);
+        let seed = u64::from_le_bytes(reader.read()?);
+        let difficulty = Difficulty::from_index(reader.read::<1>()?[0])
+            .ok_or(ReplayError::Corrupt("unknown difficulty"))?;
+        let arena = Vec2::new(f32::from_le_bytes(reader.read()?),
+                            f32::from_le_bytes(reader.read()?));
+        let final_score = u32::from_le_bytes(reader.read()?);
+        let tick_count = u32::from_le_bytes(reader.read()?) as usize;
+        let run_count = u32::from_le_bytes(reader.read()?) as usize;
+
+        if bytes.len() != HEADER_SIZE + run_count * run_size {
+            return Err(ReplayError::Corrupt("unexpected file size"));
+        }
+
+        let mut inputs = Vec::with_capacity(tick_count);
+        for _ in 0..run_count {
+            let input = if version == 1 {
+                encode_input(decode_input_mask(reader.read::<1>()?[0]))
+            } else {
+                reader.read::<2>()?.map(|axis| axis as i8)
+            };
+            let length = u16::from_le_bytes(reader.read()?);
+            inputs.extend(std::iter::repeat(input).take(length as usize));
+        }
+
+        if inputs.len() != tick_count {
+            return Err(ReplayError::Corrupt("tick count does not match inputs"));
+        }
+
+        Ok(Replay {
+            tick_hz,
+            seed,
+            difficulty,
+            arena,
+            final_score,
+            inputs,
+        })
+    }
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl ByteReader<'_> {
+    fn read<const N: usize>(&mut self) -> Result<[u8; N], ReplayError> {
+        let end = self.position + N;
+        let slice = self.bytes.get(self.position..end)
+            .ok_or(ReplayError::Corrupt("file ends early"))?;
+
+        self.position = end;
+
+        Ok(slice.try_into().unwrap())
+    }
+}
+
+fn encode_runs(inputs: &[ReplayInput]) -> Vec<(ReplayInput, u16)> {
+    let mut runs: Vec<(ReplayInput, u16)> = Vec::new();
+
+    inputs.iter().for_each(|input| {
+        match runs.last_mut() {
+            Some((last_input, length))
+                if *last_input == *input && *length < u16::MAX => *length += 1,
+            _ => runs.push((*input, 1)),
+        }
+    });
+
+    runs
+}
+
+// quantizes the movement, recorded runs play with the quantized value
+// so the playback matches exactly
+pub fn encode_input(direction: Vec2) -> ReplayInput {
+    let direction = direction.clamp(Vec2::NEG_ONE, Vec2::ONE) * INPUT_AXIS_STEPS;
+
+    [direction.x.round() as i8, direction.y.round() as i8]
+}
+
+pub fn decode_input(input: ReplayInput) -> Vec2 {
+    Vec2::new(input[0] as f32, input[1] as f32) / INPUT_AXIS_STEPS
+}
+
+fn decode_input_mask(input: u8) -> Vec2 {
+    let mut direction = Vec2::ZERO;
+
+    if input & INPUT_UP != 0 {
+        direction.y += 1.0;
+    }
+    if input & INPUT_DOWN != 0 {
+        direction.y -= 1.0;
+    }
+    if input & INPUT_LEFT != 0 {
+        direction.x -= 1.0;
+    }
+    if input & INPUT_RIGHT != 0 {
+        direction.x += 1.0;
+    }
+
+    direction
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct ReplayRecorder {
+    pub arena: Vec2,
+    pub inputs: Vec<ReplayInput>,
+}
+
+// present only when the game was started with --replay
+#[derive(Resource, Debug)]
+pub struct ReplayPlayback {
+    pub replay: Replay,
+    pub tick: usize,
+}
+
+impl ReplayPlayback {
+    pub fn new(replay: Replay) -> Self {
+        ReplayPlayback { replay, tick: 0 }
+    }
+}
\ No newline at end of file
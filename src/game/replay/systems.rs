@@ -0,0 +1,118 @@
+
+use bevy::prelude::*;
+use chrono::Local;
+
+use crate::game::replay::resources::*;
+use crate::game::player::resources::PlayerInput;
+use crate::game::rng::resources::GameRng;
+use crate::game::difficulty::Difficulty;
+use crate::game::resources::Arena;
+use crate::game::FIXED_TIMESTEP_HZ;
+use crate::events::GameOver;
+use crate::storage;
+
+const REPLAYS_DIR_NAME: &str = "replays";
+
+pub fn reset_replay(
+    mut replay_playback: ResMut<ReplayPlayback>,
+    arena: Res<Arena>
+) {
+    replay_playback.tick = 0;
+    if replay_playback.replay.tick_hz as f64 != FIXED_TIMESTEP_HZ {
+        warn!("Replay was recorded at {} ticks per second but the game \
+            runs at {}, the run will not match",
+            replay_playback.replay.tick_hz, FIXED_TIMESTEP_HZ);
+    }
+    if replay_playback.replay.arena != arena.size {
+        warn!("Replay was recorded in a {} arena but this one is {}, \
+            the run will not match", replay_playback.replay.arena, arena.size);
+    }
+}
+
+pub fn start_recording(
+    mut commands: Commands,
+    arena: Res<Arena>
+) {
+    commands.insert_resource(ReplayRecorder {
+        arena: arena.size,
+        inputs: Vec::new(),
+    });
+}
+
+pub fn record_player_input(
+    mut player_input: ResMut<PlayerInput>,
+    mut replay_recorder: ResMut<ReplayRecorder>
+) {
+    let input = encode_input(player_input.direction);
+
+    // play what was recorded, not the exact stick position
+    player_input.direction = decode_input(input);
+    replay_recorder.inputs.push(input);
+}
+
+pub fn play_back_player_input(
+    mut player_input: ResMut<PlayerInput>,
+    mut replay_playback: ResMut<ReplayPlayback>
+) {
+    let tick = replay_playback.tick;
+
+    player_input.direction = replay_playback.replay.inputs.get(tick)
+        .map(|input| decode_input(*input))
+        .unwrap_or(Vec2::ZERO);
+    replay_playback.tick += 1;
+}
+
+pub fn save_replay(
+    mut game_over_event_reader: EventReader<GameOver>,
+    replay_recorder: Option<Res<ReplayRecorder>>,
+    game_rng: Res<GameRng>,
+    difficulty: Res<Difficulty>
+) {
+    let Some(replay_recorder) = replay_recorder else {
+        return;
+    };
+
+    game_over_event_reader.read().for_each(|event| {
+        let replay = Replay {
+            tick_hz: FIXED_TIMESTEP_HZ as u16,
+            seed: game_rng.seed,
+            difficulty: *difficulty,
+            arena: replay_recorder.arena,
+            final_score: event.score,
+            inputs: replay_recorder.inputs.clone(),
+        };
+        let file_name = format!("{}-seed{}.{}",
+                                Local::now().format("%Y%m%d-%H%M%S"),
+                                game_rng.seed, REPLAY_EXTENSION);
+
+        let Some(path) = storage::data_file(REPLAYS_DIR_NAME)
+            .map(|dir| dir.join(file_name)) else {
+            return;
+        };
+
+        match replay.save(&path) {
+            Ok(()) => println!("Replay saved to {}", path.display()),
+            Err(error) => warn!("Could not save replay to {}: {}",
+                                path.display(), error),
+        }
+    });
+}
+
+pub fn check_replay_result(
+    mut game_over_event_reader: EventReader<GameOver>,
+    replay_playback: Res<ReplayPlayback>
+) {
+    game_over_event_reader.read().for_each(|event| {
+        let replay = &replay_playback.replay;
+
+        if event.score == replay.final_score
+            && replay_playback.tick == replay.inputs.len() {
+            println!("Replay finished, score {} matches the recording", event.score);
+        } else {
+            warn!("Replay diverged: score {} after {} ticks, \
+                recorded score {} after {} ticks",
+                event.score, replay_playback.tick,
+                replay.final_score, replay.inputs.len());
+        }
+    });
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use crate::game::config::ArenaConfig;
+
+// the area gameplay happens in, with the origin at the bottom left corner.
+// always the configured size, the camera scales it to fit the window so
+// every player gets the same playfield whatever their screen
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct Arena {
+    pub size: Vec2,
+}
+
+impl Arena {
+    pub fn new(size: Vec2) -> Self {
+        Arena { size }
+    }
+
+    pub fn width(&self) -> f32 {
+        self.size.x
+    }
+
+    pub fn height(&self) -> f32 {
+        self.size.y
+    }
+
+    pub fn center(&self) -> Vec2 {
+        self.size / 2.0
+    }
+}
+
+impl From<&ArenaConfig> for Arena {
+    fn from(config: &ArenaConfig) -> Self {
+        Arena::new(Vec2::new(config.width, config.height))
+    }
+}
@@ -0,0 +1,31 @@
+
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use systems::*;
+use resources::*;
+use crate::AppState;
+
+use super::player::PlayerSystemSet;
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum RngSystemSet {
+    Reseed,
+}
+
+pub struct RngPlugin;
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .init_resource::<GameRng>()
+        .init_resource::<FixedSeed>()
+        .configure_sets(OnEnter(AppState::Game),
+            RngSystemSet::Reseed
+                .before(PlayerSystemSet::PlayerSpawn))
+        .add_systems(OnEnter(AppState::Game),
+            reseed_game_rng.in_set(RngSystemSet::Reseed));
+    }
+}
\ No newline at end of file
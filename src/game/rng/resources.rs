@@ -0,0 +1,62 @@
+
+use bevy::prelude::Resource;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// every consumer draws from its own stream so the order in which
+// systems run within a frame never changes what they get
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngStream {
+    Enemy,
+    Star,
+    Sound,
+    PowerUp,
+}
+
+#[derive(Resource)]
+pub struct GameRng {
+    pub seed: u64,
+    enemy: ChaCha8Rng,
+    star: ChaCha8Rng,
+    sound: ChaCha8Rng,
+    power_up: ChaCha8Rng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        let stream = |index: u64| {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            rng.set_stream(index);
+            rng
+        };
+
+        GameRng {
+            seed,
+            enemy: stream(0),
+            star: stream(1),
+            sound: stream(2),
+            power_up: stream(3),
+        }
+    }
+
+    pub fn stream(&mut self, stream: RngStream) -> &mut ChaCha8Rng {
+        match stream {
+            RngStream::Enemy => &mut self.enemy,
+            RngStream::Star => &mut self.star,
+            RngStream::Sound => &mut self.sound,
+            RngStream::PowerUp => &mut self.power_up,
+        }
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        GameRng::new(rand::thread_rng().next_u64())
+    }
+}
+
+// set from the command line to replay a specific run
+#[derive(Resource, Debug, Default)]
+pub struct FixedSeed {
+    pub seed: Option<u64>,
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+
+use bevy::prelude::*;
+use rand::RngCore;
+
+use crate::game::rng::resources::*;
+
+pub fn reseed_game_rng(
+    mut game_rng: ResMut<GameRng>,
+    fixed_seed: Res<FixedSeed>
+) {
+    let seed = fixed_seed.seed
+        .unwrap_or_else(|| rand::thread_rng().next_u64());
+
+    *game_rng = GameRng::new(seed);
+    println!("Seed: {}", seed);
+}
\ No newline at end of file
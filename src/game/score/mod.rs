@@ -1,5 +1,5 @@
 
-use bevy::prelude::*;
+use bevy::{prelude::*, app::AppExit};
 
 pub mod resources;
 mod systems;
@@ -7,22 +7,53 @@ mod systems;
 use systems::*;
 use resources::*;
 use crate::AppState;
+use crate::game::SimulationState;
+use crate::game::player::{
+    PlayerSystemSet,
+    systems::{player_hit_star, enemy_hit_player}
+};
 
 pub struct ScorePlugin;
 
 impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
         app
-        .init_resource::<HighScores>()
         .add_systems(OnEnter(AppState::Game), 
             insert_score)
+        // counted before the hit check so a star on the killing tick makes the final score
+        .add_systems(FixedUpdate,
+            (tick_combo, score_collected_stars)
+                .chain()
+                .in_set(PlayerSystemSet::Collision)
+                .after(player_hit_star)
+                .before(enemy_hit_player)
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::Running))
+        )
+        .add_systems(Update, 
+            update_score.run_if(in_state(AppState::Game)))
+        .add_systems(OnExit(AppState::Game), 
+            remove_score);
+    }
+}
+
+// keeps the high score table, headless runs leave it alone
+pub struct HighScoresPlugin;
+
+impl Plugin for HighScoresPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .init_resource::<HighScores>()
+        .add_systems(Startup, load_high_scores)
         .add_systems(Update, (
-                update_score.run_if(in_state(AppState::Game)),
                 update_high_scores,
                 high_scores_updated,
             )
         )
-        .add_systems(OnExit(AppState::Game), 
-            remove_score);
+        .add_systems(OnExit(AppState::GameOver),
+            submit_pending_high_score)
+        // quitting from the game over screen never leaves the state
+        .add_systems(Last,
+            submit_pending_high_score.run_if(on_event::<AppExit>()));
     }
-}
\ No newline at end of file
+}
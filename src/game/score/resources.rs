@@ -1,20 +1,256 @@
 
-use bevy::prelude::Resource;
+use std::time::Duration;
+
+use bevy::{prelude::{Resource, Timer, TimerMode}, log::warn};
+use chrono::{Local, TimeZone};
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{self, StorageError};
+use crate::game::difficulty::Difficulty;
+use crate::game::config::ComboConfig;
+
+pub const MAX_HIGH_SCORES: usize = 10;
+pub const HIGH_SCORES_FILE_NAME: &str = "high_scores.ron";
+pub const HIGH_SCORES_FILE_VERSION: u32 = 1;
+pub const MAX_NAME_LENGTH: usize = 12;
+pub const DEFAULT_PLAYER_NAME: &str = "Player";
 
 #[derive(Resource, Default)]
 pub struct Score {
     pub value: u32,
 }
 
+// stars collected in quick succession, every star restarts the timer
+// and the combo is lost when it runs out
+#[derive(Resource, Debug, Default)]
+pub struct Combo {
+    pub count: u32,
+    pub best: u32,
+    pub timer: Option<Timer>,
+}
+
+impl Combo {
+    pub fn add_star(&mut self, config: &ComboConfig) {
+        self.count += 1;
+        self.best = self.best.max(self.count);
+        self.timer = Some(Timer::from_seconds(config.window, TimerMode::Once));
+    }
+
+    pub fn tick(&mut self, delta: Duration) {
+        let Some(timer) = self.timer.as_mut() else {
+            return;
+        };
+
+        if timer.tick(delta).finished() {
+            self.count = 0;
+            self.timer = None;
+        }
+    }
+
+    // what each star is worth, one more every stars_per_step stars in the combo
+    pub fn multiplier(&self, config: &ComboConfig) -> u32 {
+        (1 + self.count / config.stars_per_step.max(1)).min(config.max_multiplier)
+    }
+
+    // how much of the window is left, 0 without a combo
+    pub fn time_left_fraction(&self) -> f32 {
+        self.timer.as_ref().map_or(0.0, |timer| timer.percent_left())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct HighScoreEntry {
+    pub name: String,
+    pub score: u32,
+    // unix timestamp in seconds, 0 if unknown
+    #[serde(default)]
+    pub recorded_at: i64,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+impl HighScoreEntry {
+    pub fn date(&self) -> Option<String> {
+        if self.recorded_at == 0 {
+            return None;
+        }
+
+        Local.timestamp_opt(self.recorded_at, 0)
+            .single()
+            .map(|date| date.format("%Y-%m-%d").to_string())
+    }
+}
+
 #[derive(Resource, Debug)]
 pub struct HighScores {
-    pub scores: Vec<(String, u32)>,
+    pub scores: Vec<HighScoreEntry>,
+    // the last name entered, offered as the default for the next run
+    pub last_name: String,
+    // timestamp of the entry added by the most recent run
+    pub latest: Option<i64>,
 }
 
 impl Default for HighScores {
     fn default() -> Self {
         HighScores {
             scores: Vec::new(),
+            last_name: DEFAULT_PLAYER_NAME.to_string(),
+            latest: None,
+        }
+    }
+}
+
+// a qualifying score waiting for the player to confirm a name
+#[derive(Resource, Debug)]
+pub struct PendingHighScore {
+    pub score: u32,
+    pub name: String,
+    pub difficulty: Difficulty,
+}
+
+#[derive(Serialize, Deserialize)]
+struct HighScoresFile {
+    version: u32,
+    scores: Vec<HighScoreEntry>,
+    #[serde(default)]
+    last_name: Option<String>,
+    #[serde(default)]
+    latest: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct FileVersion {
+    version: u32,
+}
+
+impl HighScores {
+    pub fn qualifies(&self, score: u32) -> bool {
+        self.rank_of(score) < MAX_HIGH_SCORES
+    }
+
+    // returns the rank of the new entry, or None if it did not make the table
+    pub fn add(
+        &mut self,
+        name: impl Into<String>,
+        score: u32,
+        difficulty: Difficulty
+    ) -> Option<usize> {
+        let rank = self.rank_of(score);
+        if rank >= MAX_HIGH_SCORES {
+            return None;
         }
+
+        let recorded_at = Local::now().timestamp();
+
+        self.scores.insert(rank, HighScoreEntry {
+            name: name.into(),
+            score,
+            recorded_at,
+            difficulty,
+        });
+        self.scores.truncate(MAX_HIGH_SCORES);
+        self.latest = Some(recorded_at);
+
+        Some(rank)
+    }
+
+    // ties keep the older entry on top
+    fn rank_of(&self, score: u32) -> usize {
+        self.scores.partition_point(|entry| entry.score >= score)
+    }
+
+    pub fn is_latest(&self, entry: &HighScoreEntry) -> bool {
+        self.latest == Some(entry.recorded_at)
+    }
+
+    pub fn submit(&mut self, pending: &PendingHighScore) -> Option<usize> {
+        let name = sanitize_name(&pending.name)
+            .unwrap_or_else(|| self.last_name.clone());
+        let rank = self.add(name.clone(), pending.score, pending.difficulty);
+
+        self.last_name = name;
+        self.save();
+
+        rank
+    }
+
+    fn from_file(file: HighScoresFile) -> Self {
+        let mut scores = file.scores;
+        scores.sort_by_key(|entry| std::cmp::Reverse(entry.score));
+        scores.truncate(MAX_HIGH_SCORES);
+
+        let last_name = file.last_name
+            .as_deref()
+            .and_then(sanitize_name)
+            .unwrap_or_else(|| DEFAULT_PLAYER_NAME.to_string());
+
+        HighScores { scores, last_name, latest: file.latest }
+    }
+
+    pub fn load() -> Self {
+        let Some(path) = storage::data_file(HIGH_SCORES_FILE_NAME) else {
+            return HighScores::default();
+        };
+
+        match read_high_scores_file(&path) {
+            Ok(high_scores) => high_scores,
+            Err(StorageError::NotFound) => HighScores::default(),
+            Err(error) => {
+                warn!("Could not read high scores from {}: {}",
+                        path.display(), error);
+                if let Some(backup_path) = storage::back_up_file(&path) {
+                    warn!("Moved unreadable high scores to {}",
+                            backup_path.display());
+                }
+
+                HighScores::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = storage::data_file(HIGH_SCORES_FILE_NAME) else {
+            return;
+        };
+
+        let file = HighScoresFile {
+            version: HIGH_SCORES_FILE_VERSION,
+            scores: self.scores.clone(),
+            last_name: Some(self.last_name.clone()),
+            latest: self.latest,
+        };
+
+        if let Err(error) = storage::save_ron(&path, &file) {
+            warn!("Could not save high scores to {}: {}",
+                    path.display(), error);
+        }
+    }
+}
+
+fn read_high_scores_file(
+    path: &std::path::Path
+) -> Result<HighScores, StorageError> {
+    let file_version: FileVersion = storage::load_ron(path)?;
+    if file_version.version != HIGH_SCORES_FILE_VERSION {
+        return Err(StorageError::UnsupportedVersion(file_version.version));
+    }
+
+    let file: HighScoresFile = storage::load_ron(path)?;
+
+    Ok(HighScores::from_file(file))
+}
+
+// trims whitespace and enforces the length limit, None if nothing is left
+pub fn sanitize_name(name: &str) -> Option<String> {
+    let name: String = name.trim()
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(MAX_NAME_LENGTH)
+        .collect();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
     }
 }
@@ -2,14 +2,45 @@
 use bevy::prelude::*;
 
 use crate::game::score::resources::*;
-use crate::events::GameOver;
+use crate::events::{GameOver, StarCollected};
+use crate::game::difficulty::Difficulty;
+use crate::game::config::GameConfig;
 
 pub fn insert_score(mut commands: Commands) {
     commands.insert_resource(Score::default());
+    commands.insert_resource(Combo::default());
 }
 
 pub fn remove_score(mut commands: Commands) {
     commands.remove_resource::<Score>();
+    commands.remove_resource::<Combo>();
+}
+
+pub fn tick_combo(
+    mut combo: ResMut<Combo>,
+    time: Res<Time>
+) {
+    // left untouched without a combo, the HUD only redraws it on a change
+    if combo.timer.is_some() {
+        combo.tick(time.delta());
+    }
+}
+
+// each star counts toward the combo before it is scored at its multiplier
+pub fn score_collected_stars(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut score: ResMut<Score>,
+    mut combo: ResMut<Combo>,
+    config: Res<GameConfig>
+) {
+    star_collected_event_reader.read().for_each(|_| {
+        combo.add_star(&config.combo);
+        score.value += combo.multiplier(&config.combo);
+    });
+}
+
+pub fn load_high_scores(mut high_scores: ResMut<HighScores>) {
+    *high_scores = HighScores::load();
 }
 
 pub fn update_score(score: Res<Score>) {
@@ -20,14 +51,37 @@ pub fn update_score(score: Res<Score>) {
 }
 
 pub fn update_high_scores(
+    mut commands: Commands,
     mut game_over_event_reader: EventReader<GameOver>,
-    mut high_scores: ResMut<HighScores>
+    mut high_scores: ResMut<HighScores>,
+    difficulty: Res<Difficulty>
 ) {
     game_over_event_reader.read().for_each(|event| {
-        high_scores.scores.push(("Player".to_string(), event.score));
+        if high_scores.qualifies(event.score) {
+            commands.insert_resource(PendingHighScore {
+                score: event.score,
+                name: high_scores.last_name.clone(),
+                difficulty: *difficulty,
+            });
+        } else if high_scores.latest.take().is_some() {
+            // the last run did not make the table, nothing to highlight
+            high_scores.save();
+        }
     })
 }
 
+// keeps a qualifying score if the player leaves without confirming a name
+pub fn submit_pending_high_score(
+    mut commands: Commands,
+    pending_high_score: Option<Res<PendingHighScore>>,
+    mut high_scores: ResMut<HighScores>
+) {
+    if let Some(pending_high_score) = pending_high_score {
+        high_scores.submit(&pending_high_score);
+        commands.remove_resource::<PendingHighScore>();
+    }
+}
+
 pub fn high_scores_updated(high_scores: Res<HighScores>) {
     if high_scores.is_changed() {
         println!("High Scores: {:?}", high_scores);
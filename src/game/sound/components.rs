@@ -0,0 +1,15 @@
+use bevy::prelude::Component;
+
+use crate::events::SoundEffect;
+use crate::settings::resources::VolumeBus;
+
+// anything playing audio, so its volume follows the settings
+#[derive(Component)]
+pub struct SoundBus {
+    pub bus: VolumeBus,
+}
+
+#[derive(Component)]
+pub struct PlayingSound {
+    pub sound: SoundEffect,
+}
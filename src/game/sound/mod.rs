@@ -0,0 +1,20 @@
+pub mod components;
+pub mod resources;
+mod systems;
+
+use bevy::prelude::*;
+
+use systems::*;
+
+// plays every sound in the game on a volume bus from the settings
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_systems(Update, (
+            (play_gameplay_sounds, play_sounds).chain(),
+            update_sound_volumes
+        ));
+    }
+}
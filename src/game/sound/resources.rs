@@ -0,0 +1,12 @@
+use crate::events::SoundEffect;
+
+// how many of a sound can play at once, more are dropped
+// so a crowd of enemies bouncing together is not deafening
+pub fn max_instances(sound: SoundEffect) -> usize {
+    match sound {
+        SoundEffect::EnemyBounce => 2,
+        SoundEffect::StarCollected => 2,
+        SoundEffect::PlayerHit => 1,
+        SoundEffect::PowerUpCollected => 1,
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use bevy::{prelude::*, audio::Volume};
+use rand::Rng;
+
+use crate::events::{
+    PlaySound,
+    SoundEffect,
+    StarCollected,
+    EnemyBounced,
+    PlayerHit,
+    PowerUpCollected,
+};
+use crate::game::rng::resources::{GameRng, RngStream};
+use crate::game::sound::{components::*, resources::*};
+use crate::settings::resources::{Settings, VolumeBus};
+use crate::loading::resources::GameAssets;
+
+// what gameplay sounds like, a plugin in its place can hear it differently
+pub fn play_gameplay_sounds(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut enemy_bounced_event_reader: EventReader<EnemyBounced>,
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut power_up_collected_event_reader: EventReader<PowerUpCollected>,
+    mut play_sound_event_writer: EventWriter<PlaySound>
+) {
+    let sounds = star_collected_event_reader.read().map(|_| SoundEffect::StarCollected)
+        .chain(enemy_bounced_event_reader.read().map(|_| SoundEffect::EnemyBounce))
+        .chain(player_hit_event_reader.read().map(|_| SoundEffect::PlayerHit))
+        .chain(power_up_collected_event_reader.read().map(|_| SoundEffect::PowerUpCollected));
+
+    play_sound_event_writer.send_batch(sounds.map(|sound| PlaySound { sound }));
+}
+
+pub fn play_sounds(
+    mut commands: Commands,
+    mut play_sound_event_reader: EventReader<PlaySound>,
+    game_assets: Res<GameAssets>,
+    playing_sound_query: Query<&PlayingSound>,
+    settings: Res<Settings>,
+    mut game_rng: ResMut<GameRng>
+) {
+    let volume = settings.output_volume(VolumeBus::Sfx);
+    let mut playing = HashMap::new();
+    playing_sound_query.for_each(|playing_sound| {
+        *playing.entry(playing_sound.sound).or_insert(0) += 1;
+    });
+
+    play_sound_event_reader.read().for_each(|event| {
+        let variants = game_assets.sound_variants(event.sound);
+        // picked before any sound is dropped so the stream
+        // advances the same whatever the settings
+        let variant = game_rng.stream(RngStream::Sound).gen_range(0..variants.len());
+        let count = playing.entry(event.sound).or_insert(0);
+
+        if volume <= 0.0 || *count >= max_instances(event.sound) {
+            return;
+        }
+        *count += 1;
+
+        commands.spawn((
+            AudioBundle {
+                source: variants[variant].clone(),
+                settings: PlaybackSettings::DESPAWN
+                    .with_volume(Volume::new_relative(volume)),
+            },
+            SoundBus { bus: VolumeBus::Sfx },
+            PlayingSound { sound: event.sound }
+        ));
+    });
+}
+
+// changes reach the sounds already playing, not just the next ones
+pub fn update_sound_volumes(
+    settings: Res<Settings>,
+    sink_query: Query<(&AudioSink, &SoundBus)>
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    sink_query.for_each(|(sink, sound_bus)| {
+        sink.set_volume(settings.output_volume(sound_bus.bus));
+    });
+}
@@ -0,0 +1,21 @@
+mod systems;
+
+use bevy::prelude::*;
+
+use systems::*;
+
+// gives gameplay entities something to draw, gameplay itself never needs it
+pub struct SpritesPlugin;
+
+impl Plugin for SpritesPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_systems(Update, (
+            attach_player_sprite,
+            attach_enemy_sprites,
+            attach_star_sprites,
+            attach_power_up_sprites,
+            blink_invulnerable_player
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+
+use crate::game::physics::components::Position;
+use crate::game::player::components::{Invulnerable, Player};
+use crate::game::enemy::components::Enemy;
+use crate::game::star::components::Star;
+use crate::game::power_up::{components::PowerUp, POWER_UP_SIZE};
+use crate::game::config::GameConfig;
+use crate::loading::resources::GameAssets;
+
+// on and off cycles per second while the player can not be hit
+const BLINK_HZ: f32 = 8.0;
+
+pub fn attach_player_sprite(
+    mut commands: Commands,
+    player_query: Query<(Entity, &Position), Added<Player>>,
+    game_assets: Res<GameAssets>
+) {
+    player_query.for_each(|(entity, position)| {
+        attach_sprite(&mut commands, entity, position,
+                    game_assets.player_sprite.clone());
+    });
+}
+
+pub fn attach_enemy_sprites(
+    mut commands: Commands,
+    enemy_query: Query<(Entity, &Position, &Enemy), Added<Enemy>>,
+    game_assets: Res<GameAssets>,
+    config: Res<GameConfig>
+) {
+    enemy_query.for_each(|(entity, position, enemy)| {
+        let archetype = &config.enemy.archetypes[enemy.archetype];
+        let (red, green, blue) = archetype.color;
+        let texture = game_assets.enemy_sprites.get(&archetype.sprite)
+            .cloned()
+            .unwrap_or_else(|| game_assets.enemy_sprite.clone());
+
+        commands.entity(entity).insert(SpriteBundle {
+            transform: Transform::from_translation(position.current.extend(0.0)),
+            texture,
+            sprite: Sprite {
+                color: Color::rgb(red, green, blue),
+                custom_size: Some(Vec2::splat(enemy.size)),
+                ..default()
+            },
+            ..default()
+        });
+    });
+}
+
+pub fn attach_star_sprites(
+    mut commands: Commands,
+    star_query: Query<(Entity, &Position), Added<Star>>,
+    game_assets: Res<GameAssets>
+) {
+    star_query.for_each(|(entity, position)| {
+        attach_sprite(&mut commands, entity, position,
+                    game_assets.star_sprite.clone());
+    });
+}
+
+// the star sprite tinted by kind, until power-ups get art of their own
+pub fn attach_power_up_sprites(
+    mut commands: Commands,
+    power_up_query: Query<(Entity, &Position, &PowerUp), Added<PowerUp>>,
+    game_assets: Res<GameAssets>
+) {
+    power_up_query.for_each(|(entity, position, power_up)| {
+        commands.entity(entity).insert(SpriteBundle {
+            transform: Transform::from_translation(position.current.extend(0.0)),
+            texture: game_assets.star_sprite.clone(),
+            sprite: Sprite {
+                color: power_up.kind.color(),
+                custom_size: Some(Vec2::splat(POWER_UP_SIZE)),
+                ..default()
+            },
+            ..default()
+        });
+    });
+}
+
+pub fn blink_invulnerable_player(
+    mut player_query: Query<(&mut Visibility, Option<&Invulnerable>), With<Player>>
+) {
+    if let Ok((mut visibility, invulnerable)) = player_query.get_single_mut() {
+        let visible = invulnerable.map_or(true, |invulnerable| {
+            (invulnerable.timer.elapsed_secs() * BLINK_HZ * 2.0) as u32 % 2 == 0
+        });
+
+        visibility.set_if_neq(if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+}
+
+fn attach_sprite(
+    commands: &mut Commands,
+    entity: Entity,
+    position: &Position,
+    texture: Handle<Image>
+) {
+    commands.entity(entity).insert(SpriteBundle {
+        transform: Transform::from_translation(position.current.extend(0.0)),
+        texture,
+        ..default()
+    });
+}
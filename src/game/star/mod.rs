@@ -6,11 +6,11 @@ pub mod resources;
 pub mod systems;
 
 use systems::*;
-use resources::*;
 use crate::AppState;
 use crate::game::SimulationState;
+use crate::game::rng::RngSystemSet;
+use crate::game::player::PlayerSystemSet;
 
-pub const NUM_OF_STARS: usize = 10;
 pub const STAR_SIZE: f32 = 30.0; // this is the star sprite size
 
 pub struct StarPlugin;
@@ -18,17 +18,22 @@ pub struct StarPlugin;
 impl Plugin for StarPlugin {
     fn build(&self, app: &mut App) {
         app
-        .init_resource::<StarSpawnTimer>()
-        .add_systems(OnEnter(AppState::Game),
-            spawn_stars)
-        .add_systems(Update, (
+        .add_systems(OnEnter(AppState::Game), (
+            insert_star_spawn_timer,
+            spawn_stars.after(RngSystemSet::Reseed)
+        ))
+        .add_systems(FixedUpdate, (
                     tick_star_spawn_timer,
                     spawn_stars_over_time
                 )
+                .chain()
+                .after(PlayerSystemSet::Collision)
                 .run_if(in_state(AppState::Game))
                 .run_if(in_state(SimulationState::Running))
         )
-        .add_systems(OnExit(AppState::Game), 
-            despawn_stars);
+        .add_systems(OnExit(AppState::Game), (
+            despawn_stars,
+            remove_star_spawn_timer
+        ));
     }
 }
\ No newline at end of file
@@ -1,17 +1,15 @@
 
 use bevy::prelude::{Resource, Timer, TimerMode};
 
-pub const STAR_SPAWN_TIME: f32 = 1.0;
-
 #[derive(Resource)]
 pub struct StarSpawnTimer {
     pub timer: Timer,
 }
 
-impl Default for StarSpawnTimer {
-    fn default() -> Self {
+impl StarSpawnTimer {
+    pub fn new(spawn_time: f32) -> Self {
         StarSpawnTimer {
-            timer: Timer::from_seconds(STAR_SPAWN_TIME,
+            timer: Timer::from_seconds(spawn_time,
                                  TimerMode::Repeating),
         }
     }
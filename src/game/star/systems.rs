@@ -1,24 +1,29 @@
 
-use bevy::{prelude::*, window::PrimaryWindow};
-use rand::random;
+use bevy::prelude::*;
+use rand::Rng;
 
 use crate::game::star::{
     components::*, 
     resources::*,
-    NUM_OF_STARS,
     STAR_SIZE
 };
 use crate::game::enemy::confine_actor_axis;
+use crate::game::config::GameConfig;
+use crate::game::difficulty::Difficulty;
+use crate::game::rng::resources::{GameRng, RngStream};
+use crate::game::physics::components::{Position, Collider, CollisionLayer};
+use crate::game::resources::Arena;
 
 pub fn spawn_stars(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>
+    arena: Res<Arena>,
+    config: Res<GameConfig>,
+    mut game_rng: ResMut<GameRng>
 ) {
-    let window = window_query.get_single().unwrap();
+    let rng = game_rng.stream(RngStream::Star);
 
-    (0..NUM_OF_STARS).into_iter().for_each(|_| {
-        star_spawn(window, &mut commands, &asset_server);
+    (0..config.star.count).for_each(|_| {
+        star_spawn(&arena, &mut commands, rng);
     });
 }
 
@@ -31,6 +36,20 @@ pub fn despawn_stars(
     })
 }
 
+pub fn insert_star_spawn_timer(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>
+) {
+    let spawn_time = config.star_spawn_time(*difficulty);
+
+    commands.insert_resource(StarSpawnTimer::new(spawn_time));
+}
+
+pub fn remove_star_spawn_timer(mut commands: Commands) {
+    commands.remove_resource::<StarSpawnTimer>();
+}
+
 pub fn tick_star_spawn_timer(
     mut star_spawn_timer: ResMut<StarSpawnTimer>,
     time: Res<Time>
@@ -40,36 +59,35 @@ pub fn tick_star_spawn_timer(
 
 pub fn spawn_stars_over_time(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-    star_spawn_timer: Res<StarSpawnTimer>
+    arena: Res<Arena>,
+    star_spawn_timer: Res<StarSpawnTimer>,
+    mut game_rng: ResMut<GameRng>
 ) {
     if star_spawn_timer.timer.finished() {
-        let window = window_query.get_single().unwrap();
-        star_spawn(window, &mut commands, &asset_server);
+        let rng = game_rng.stream(RngStream::Star);
+        star_spawn(&arena, &mut commands, rng);
     }
 }
 
 
 fn star_spawn(
-    window: &Window, 
+    arena: &Arena, 
     commands: &mut Commands, 
-    asset_server: &Res<AssetServer>
+    rng: &mut impl Rng
 ) {
-    let window_with = window.width();
-    let window_height = window.height();
-    let mut random_x = random::<f32>() * window_with;
-    let mut random_y = random::<f32>() * window_height;
+    let arena_width = arena.width();
+    let arena_height = arena.height();
+    let mut random_x = rng.gen::<f32>() * arena_width;
+    let mut random_y = rng.gen::<f32>() * arena_height;
 
-    confine_actor_axis(&mut random_x, window_with, STAR_SIZE);
-    confine_actor_axis(&mut random_y, window_height, STAR_SIZE);
+    confine_actor_axis(&mut random_x, arena_width, STAR_SIZE);
+    confine_actor_axis(&mut random_y, arena_height, STAR_SIZE);
 
     commands.spawn((
-        SpriteBundle {
-            transform: Transform::from_xyz(random_x, random_y, 0.0),
-            texture: asset_server.load("sprites/star.png"),
-            ..default()
-        },
-        Star {}
+        Position::new(Vec2::new(random_x, random_y)),
+        Star {},
+        // the sprite is square, the pickup goes by its corners too
+        Collider::aabb(Vec2::splat(STAR_SIZE / 2.0), CollisionLayer::STAR,
+                    CollisionLayer::NONE)
     ));
 }
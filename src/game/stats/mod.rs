@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+pub mod resources;
+pub mod systems;
+
+use systems::*;
+use resources::*;
+use crate::AppState;
+
+// counts what happens over a run from the gameplay events
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .init_resource::<RunStats>()
+        .add_systems(OnEnter(AppState::Game),
+            reset_run_stats)
+        // every frame rather than every tick, so no event expires unread
+        .add_systems(Update,
+            update_run_stats.run_if(in_state(AppState::Game)));
+    }
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::Resource;
+
+// kept once the run is over so it can still be reported
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct RunStats {
+    pub stars_collected: u32,
+    pub enemies_spawned: u32,
+    pub enemy_bounces: u32,
+    pub hits_taken: u32,
+    // hits a shield charge took instead of a life
+    pub hits_absorbed: u32,
+    pub power_ups_collected: u32,
+}
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::game::stats::resources::*;
+use crate::events::{
+    StarCollected,
+    EnemyBounced,
+    EnemySpawned,
+    PlayerHit,
+    PowerUpCollected,
+};
+
+pub fn reset_run_stats(mut run_stats: ResMut<RunStats>) {
+    *run_stats = RunStats::default();
+}
+
+pub fn update_run_stats(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut enemy_bounced_event_reader: EventReader<EnemyBounced>,
+    mut enemy_spawned_event_reader: EventReader<EnemySpawned>,
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut power_up_collected_event_reader: EventReader<PowerUpCollected>,
+    mut run_stats: ResMut<RunStats>
+) {
+    run_stats.stars_collected += star_collected_event_reader.read().count() as u32;
+    run_stats.enemy_bounces += enemy_bounced_event_reader.read().count() as u32;
+    run_stats.enemies_spawned += enemy_spawned_event_reader.read().count() as u32;
+    run_stats.power_ups_collected += power_up_collected_event_reader.read().count() as u32;
+
+    player_hit_event_reader.read().for_each(|event| {
+        if event.absorbed {
+            run_stats.hits_absorbed += 1;
+        } else {
+            run_stats.hits_taken += 1;
+        }
+    });
+}
@@ -1,7 +1,9 @@
 
-use bevy::prelude::*;
+use bevy::{prelude::*, window::WindowFocused};
 
 use crate::game::SimulationState;
+use crate::settings::resources::Settings;
+use crate::controls::resources::InputAction;
 
 pub fn pause_simulation(
     mut simulation_next_state: ResMut<NextState<SimulationState>>
@@ -16,11 +18,11 @@ pub fn resume_simulation(
 }
 
 pub fn toggle_simulation(
-    keyboard_input: Res<Input<KeyCode>>,
+    action_input: Res<Input<InputAction>>,
     simulation_state: Res<State<SimulationState>>,
     simulation_next_state: ResMut<NextState<SimulationState>>
 ) {
-    if keyboard_input.just_pressed(KeyCode::Space) {
+    if action_input.just_pressed(InputAction::Pause) {
         let state = simulation_state.get();
         if *state == SimulationState::Running {
             pause_simulation(simulation_next_state);
@@ -31,4 +33,21 @@ pub fn toggle_simulation(
             println!("Running");
         }
     }
-}
\ No newline at end of file
+}
+// alt-tabbing away mid run should not cost the player the run
+pub fn pause_on_focus_loss(
+    mut window_focused_event_reader: EventReader<WindowFocused>,
+    settings: Res<Settings>,
+    simulation_state: Res<State<SimulationState>>,
+    simulation_next_state: ResMut<NextState<SimulationState>>
+) {
+    let focus_lost = window_focused_event_reader.read()
+        .last()
+        .is_some_and(|event| !event.focused);
+
+    if focus_lost && settings.pause_on_focus_loss &&
+        *simulation_state.get() == SimulationState::Running {
+            pause_simulation(simulation_next_state);
+            println!("Paused, window lost focus");
+    }
+}
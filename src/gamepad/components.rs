@@ -0,0 +1,10 @@
+use bevy::prelude::{Component, Timer};
+
+#[derive(Component)]
+pub struct GamepadToast {
+    pub timer: Timer,
+}
+
+// while one is open only the buttons inside it can be focused
+#[derive(Component)]
+pub struct Modal {}
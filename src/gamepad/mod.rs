@@ -0,0 +1,46 @@
+pub mod components;
+pub mod resources;
+mod systems;
+
+use bevy::{prelude::*, ui::UiSystem};
+
+use systems::*;
+use resources::*;
+use crate::controls::{ControlsSystemSet, resources::PendingRebind};
+use crate::game::score::resources::PendingHighScore;
+
+// stick value that counts as a press when moving between menu buttons
+pub const MENU_STICK_THRESHOLD: f32 = 0.5;
+pub const TOAST_SECONDS: f32 = 3.0;
+
+// drives the menus with the menu actions, so with a gamepad or the
+// keyboard, and reports gamepads coming and going
+pub struct GamepadPlugin;
+
+impl Plugin for GamepadPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .init_resource::<MenuFocus>()
+        // after the mouse had its say so a gamepad press is not reset
+        // before the menus see it
+        .add_systems(PreUpdate, (
+                    move_menu_focus,
+                    highlight_focused_button,
+                    press_focused_button
+                )
+                .chain()
+                .after(UiSystem::Focus)
+                .after(ControlsSystemSet::Actions)
+                // typed letters and keys being bound are not menu input
+                .run_if(not(resource_exists::<PendingHighScore>()))
+                .run_if(not(resource_exists::<PendingRebind>()))
+        )
+        .add_systems(Update, (
+            back_out_of_menu
+                .run_if(not(resource_exists::<PendingHighScore>()))
+                .run_if(not(resource_exists::<PendingRebind>())),
+            show_gamepad_toast,
+            despawn_expired_toasts
+        ));
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::{Entity, Resource};
+
+// the menu button a gamepad would press. empty until a gamepad is used
+// so mouse players never see a highlight they did not ask for
+#[derive(Resource, Debug, Default)]
+pub struct MenuFocus {
+    pub button: Option<Entity>,
+    // the stick has to return to the center before it moves focus again
+    pub stick_held: bool,
+}
@@ -0,0 +1,281 @@
+use bevy::{prelude::*, input::gamepad::{GamepadConnection, GamepadConnectionEvent}};
+
+use crate::gamepad::{
+    components::*,
+    resources::*,
+    MENU_STICK_THRESHOLD,
+    TOAST_SECONDS
+};
+use crate::game::SimulationState;
+use crate::controls::resources::InputAction;
+use crate::settings::resources::SettingsMenuOpen;
+use crate::main_menu::styles::get_small_button_text_style;
+use crate::AppState;
+use crate::loading::resources::GameAssets;
+
+const TOAST_COLOR: Color = Color::rgba(0.1, 0.1, 0.1, 0.9);
+
+// ui space, y grows downwards
+fn read_menu_direction(
+    action_input: &Input<InputAction>,
+    gamepads: &Gamepads,
+    gamepad_axes: &Axis<GamepadAxis>,
+    menu_focus: &mut MenuFocus
+) -> Option<Vec2> {
+    let actions = [
+        (InputAction::MoveUp, Vec2::NEG_Y),
+        (InputAction::MoveDown, Vec2::Y),
+        (InputAction::MoveLeft, Vec2::NEG_X),
+        (InputAction::MoveRight, Vec2::X),
+    ];
+
+    if let Some((_, direction)) = actions.iter()
+        .find(|(action, _)| action_input.just_pressed(*action)) {
+        return Some(*direction);
+    }
+    let stick = gamepads.iter()
+        .map(|gamepad| {
+            let axis = |axis_type| gamepad_axes
+                .get(GamepadAxis::new(gamepad, axis_type))
+                .unwrap_or(0.0);
+            Vec2::new(axis(GamepadAxisType::LeftStickX),
+                    -axis(GamepadAxisType::LeftStickY))
+        })
+        .find(|stick| stick.length() > MENU_STICK_THRESHOLD);
+
+    match stick {
+        Some(_) if menu_focus.stick_held => None,
+        Some(stick) => {
+            menu_focus.stick_held = true;
+            // snap to the main axis so a slightly off stick still goes straight
+            if stick.x.abs() > stick.y.abs() {
+                Some(Vec2::new(stick.x.signum(), 0.0))
+            } else {
+                Some(Vec2::new(0.0, stick.y.signum()))
+            }
+        },
+        None => {
+            menu_focus.stick_held = false;
+            None
+        }
+    }
+}
+
+pub fn move_menu_focus(
+    action_input: Res<Input<InputAction>>,
+    gamepads: Res<Gamepads>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    mut menu_focus: ResMut<MenuFocus>,
+    mut button_query: Query<(Entity, &GlobalTransform, &mut Interaction), With<Button>>,
+    modal_query: Query<Entity, With<Modal>>,
+    parent_query: Query<&Parent>
+) {
+    let Some(direction) = read_menu_direction(&action_input, &gamepads,
+                                            &gamepad_axes, &mut menu_focus) else {
+        return;
+    };
+
+    let focusable = |entity| is_focusable(entity, &modal_query, &parent_query);
+    let focused = menu_focus.button
+        .filter(|button| focusable(*button))
+        .and_then(|button| button_query.get(button).ok())
+        .map(|(entity, transform, _)| (entity, transform.translation().truncate()));
+
+    let next = match focused {
+        // the nearest button in that direction, favouring ones in line
+        Some((focused_entity, focused_position)) => button_query.iter()
+            .filter(|(entity, _, _)| *entity != focused_entity && focusable(*entity))
+            .filter_map(|(entity, transform, _)| {
+                let offset = transform.translation().truncate() - focused_position;
+                let along = offset.dot(direction);
+                let across = offset.perp_dot(direction).abs();
+
+                (along > 0.0).then_some((entity, along + across * 2.0))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(entity, _)| entity),
+        None => first_button(&button_query, focusable),
+    };
+
+    if let Some(next) = next {
+        if let Some(button) = menu_focus.button {
+            if let Ok((_, _, mut interaction)) = button_query.get_mut(button) {
+                interaction.set_if_neq(Interaction::None);
+            }
+        }
+        menu_focus.button = Some(next);
+    }
+}
+
+// a button under an open modal, like the settings panel, is out of reach
+fn is_focusable(
+    entity: Entity,
+    modal_query: &Query<Entity, With<Modal>>,
+    parent_query: &Query<&Parent>
+) -> bool {
+    modal_query.is_empty() || parent_query.iter_ancestors(entity)
+        .any(|ancestor| modal_query.contains(ancestor))
+}
+
+// the top left button, where a fresh menu starts
+fn first_button(
+    button_query: &Query<(Entity, &GlobalTransform, &mut Interaction), With<Button>>,
+    focusable: impl Fn(Entity) -> bool
+) -> Option<Entity> {
+    button_query.iter()
+        .filter(|(entity, _, _)| focusable(*entity))
+        .min_by(|(_, a, _), (_, b, _)| {
+            let a = a.translation();
+            let b = b.translation();
+            a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x))
+        })
+        .map(|(entity, _, _)| entity)
+}
+
+// shows the focus the same way the mouse would, so every menu
+// keeps drawing its buttons with its own colors
+pub fn highlight_focused_button(
+    mut menu_focus: ResMut<MenuFocus>,
+    mut button_query: Query<&mut Interaction, With<Button>>
+) {
+    let Some(button) = menu_focus.button else {
+        return;
+    };
+
+    match button_query.get_mut(button) {
+        Ok(mut interaction) => {
+            interaction.set_if_neq(Interaction::Hovered);
+        },
+        // the menu was closed
+        Err(_) => menu_focus.button = None,
+    }
+}
+
+pub fn press_focused_button(
+    action_input: Res<Input<InputAction>>,
+    mut menu_focus: ResMut<MenuFocus>,
+    mut button_query: Query<(Entity, &GlobalTransform, &mut Interaction), With<Button>>,
+    modal_query: Query<Entity, With<Modal>>,
+    parent_query: Query<&Parent>
+) {
+    if !action_input.just_pressed(InputAction::Confirm) {
+        return;
+    }
+
+    // the first press on a fresh menu only shows where the focus is
+    let focusable = |entity| is_focusable(entity, &modal_query, &parent_query);
+    let Some(button) = menu_focus.button.filter(|button| focusable(*button)) else {
+        menu_focus.button = first_button(&button_query, focusable);
+        return;
+    };
+
+    if let Ok((_, _, mut interaction)) = button_query.get_mut(button) {
+        *interaction = Interaction::Pressed;
+    }
+}
+
+pub fn back_out_of_menu(
+    mut commands: Commands,
+    action_input: Res<Input<InputAction>>,
+    settings_menu_open: Option<Res<SettingsMenuOpen>>,
+    app_state: Res<State<AppState>>,
+    simulation_state: Res<State<SimulationState>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut next_simulation_state: ResMut<NextState<SimulationState>>
+) {
+    if !action_input.just_pressed(InputAction::Back) {
+        return;
+    }
+
+    // the settings panel closes first, back to the menu it was opened from
+    if settings_menu_open.is_some() {
+        commands.remove_resource::<SettingsMenuOpen>();
+        return;
+    }
+
+    match app_state.get() {
+        AppState::Game if *simulation_state.get() == SimulationState::Paused =>
+            next_simulation_state.set(SimulationState::Running),
+        AppState::GameOver | AppState::HighScores | AppState::Controls =>
+            next_app_state.set(AppState::MainMenu),
+        _ => {},
+    }
+}
+
+pub fn show_gamepad_toast(
+    mut commands: Commands,
+    mut connection_event_reader: EventReader<GamepadConnectionEvent>,
+    toast_query: Query<Entity, With<GamepadToast>>,
+    game_assets: Res<GameAssets>
+) {
+    let Some(event) = connection_event_reader.read().last() else {
+        return;
+    };
+
+    let message = match &event.connection {
+        GamepadConnection::Connected(info) => format!("Gamepad connected: {}", info.name),
+        GamepadConnection::Disconnected => "Gamepad disconnected".to_string(),
+    };
+    println!("{}", message);
+
+    // only the latest news is worth showing
+    toast_query.for_each(|entity| {
+        commands.entity(entity).despawn_recursive();
+    });
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(24.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            z_index: ZIndex::Global(10),
+            ..default()
+        },
+        GamepadToast {
+            timer: Timer::from_seconds(TOAST_SECONDS, TimerMode::Once),
+        }
+    ))
+    .with_children(|parent| {
+        parent.spawn(
+            NodeBundle {
+                style: Style {
+                    padding: UiRect::all(Val::Px(12.0)),
+                    ..default()
+                },
+                background_color: TOAST_COLOR.into(),
+                ..default()
+            }
+        )
+        .with_children(|parent| {
+            parent.spawn(
+                TextBundle {
+                    text: Text {
+                        sections: vec![
+                            TextSection::new(
+                                message,
+                                get_small_button_text_style(&game_assets)
+                            )],
+                        alignment: TextAlignment::Center,
+                        ..default()
+                    },
+                    ..default()
+                });
+        });
+    });
+}
+
+pub fn despawn_expired_toasts(
+    mut commands: Commands,
+    mut toast_query: Query<(Entity, &mut GamepadToast)>,
+    time: Res<Time>
+) {
+    toast_query.for_each_mut(|(entity, mut toast)| {
+        if toast.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    });
+}
@@ -0,0 +1,37 @@
+pub mod resources;
+mod systems;
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+
+use systems::*;
+use resources::*;
+use crate::AppState;
+use crate::game::{SimulationState, FIXED_TIMESTEP_HZ};
+use crate::game::stats::systems::update_run_stats;
+
+// plays runs back to back without a window and exits when they are done
+pub struct HeadlessPlugin {
+    pub runs: u32,
+}
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        // every update is exactly one fixed tick, as fast as the cpu allows
+        .insert_resource(TimeUpdateStrategy::ManualDuration(
+            Duration::from_secs_f64(1.0 / FIXED_TIMESTEP_HZ)))
+        .insert_resource(HeadlessRuns::new(self.runs))
+        .add_systems(Startup, start_headless_run)
+        .add_systems(FixedUpdate, 
+            count_run_ticks
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::Running))
+        )
+        // the stats from the killing tick are in before the run is reported
+        .add_systems(Update, report_headless_run.after(update_run_stats))
+        .add_systems(OnEnter(AppState::GameOver), 
+            start_next_headless_run);
+    }
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Debug)]
+pub struct HeadlessRuns {
+    pub total: u32,
+    pub completed: u32,
+    pub ticks: u32,
+}
+
+impl HeadlessRuns {
+    pub fn new(total: u32) -> Self {
+        HeadlessRuns {
+            total: total.max(1),
+            completed: 0,
+            ticks: 0,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.completed >= self.total
+    }
+}
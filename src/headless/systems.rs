@@ -0,0 +1,60 @@
+use bevy::{prelude::*, app::AppExit};
+
+use crate::headless::resources::*;
+use crate::game::difficulty::Difficulty;
+use crate::game::rng::resources::{FixedSeed, GameRng};
+use crate::game::FIXED_TIMESTEP_HZ;
+use crate::game::stats::resources::RunStats;
+use crate::events::GameOver;
+use crate::AppState;
+
+pub fn start_headless_run(
+    mut next_app_state: ResMut<NextState<AppState>>
+) {
+    next_app_state.set(AppState::Game);
+}
+
+pub fn count_run_ticks(mut headless_runs: ResMut<HeadlessRuns>) {
+    headless_runs.ticks += 1;
+}
+
+// one line per run so scripts can parse the results
+pub fn report_headless_run(
+    mut game_over_event_reader: EventReader<GameOver>,
+    mut headless_runs: ResMut<HeadlessRuns>,
+    game_rng: Res<GameRng>,
+    difficulty: Res<Difficulty>,
+    run_stats: Res<RunStats>
+) {
+    game_over_event_reader.read().for_each(|event| {
+        headless_runs.completed += 1;
+        println!("Run {}/{}: seed {} difficulty {} score {} best combo {} ticks {} ({:.1}s) \
+                stars {} hits {} absorbed {} bounces {} enemies {} power-ups {}",
+                headless_runs.completed, headless_runs.total,
+                game_rng.seed, difficulty.label(), event.score,
+                event.best_combo, headless_runs.ticks,
+                headless_runs.ticks as f64 / FIXED_TIMESTEP_HZ,
+                run_stats.stars_collected, run_stats.hits_taken,
+                run_stats.hits_absorbed, run_stats.enemy_bounces,
+                run_stats.enemies_spawned, run_stats.power_ups_collected);
+        headless_runs.ticks = 0;
+    });
+}
+
+pub fn start_next_headless_run(
+    headless_runs: Res<HeadlessRuns>,
+    mut fixed_seed: ResMut<FixedSeed>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut app_exit_event_writer: EventWriter<AppExit>
+) {
+    if headless_runs.is_finished() {
+        app_exit_event_writer.send(AppExit);
+        return;
+    }
+
+    // a fixed seed makes a batch reproducible without repeating one run
+    if let Some(seed) = fixed_seed.seed.as_mut() {
+        *seed = seed.wrapping_add(1);
+    }
+    next_app_state.set(AppState::Game);
+}
@@ -0,0 +1,8 @@
+
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct HighScoresMenu {}
+
+#[derive(Component)]
+pub struct BackButton {}
\ No newline at end of file
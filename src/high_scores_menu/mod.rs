@@ -0,0 +1,25 @@
+
+mod components;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use systems::layout::*;
+use systems::interactions::*;
+
+pub struct HighScoresMenuPlugin;
+
+impl Plugin for HighScoresMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_systems(OnEnter(AppState::HighScores),
+                    spawn_high_scores_menu)
+        .add_systems(Update,
+                interact_with_back_button
+                .run_if(in_state(AppState::HighScores))
+        )
+        .add_systems(OnExit(AppState::HighScores), 
+                    despawn_high_scores_menu);
+    }
+}
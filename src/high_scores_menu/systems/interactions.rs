@@ -0,0 +1,26 @@
+
+use bevy::prelude::*;
+
+use crate::{
+    main_menu::styles::*,
+    high_scores_menu::components::*,
+    AppState
+};
+
+pub fn interact_with_back_button(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), 
+                        (Changed<Interaction>, With<BackButton>)>,
+    mut next_app_state: ResMut<NextState<AppState>>
+) {
+    if let Ok((interaction, mut background_color)) = 
+                button_query.get_single_mut() {
+                    *background_color = match *interaction {
+                    Interaction::Pressed => {
+                        next_app_state.set(AppState::MainMenu);
+                        PRESSED_BUTTON_COLOR.into()
+                    },
+                    Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+                    Interaction::None => NORMAL_BUTTON_COLOR.into(),
+                }
+    }
+}
\ No newline at end of file
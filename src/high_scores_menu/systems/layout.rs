@@ -0,0 +1,183 @@
+
+use bevy::prelude::*;
+
+use crate::{
+    high_scores_menu::components::*,
+    main_menu::styles::*,
+    game::score::resources::{HighScores, HighScoreEntry},
+};
+use crate::loading::resources::GameAssets;
+
+const ROW_COLOR: Color = Color::rgba(0.15, 0.15, 0.15, 0.6);
+const LATEST_ROW_COLOR: Color = Color::rgba(0.35, 0.75, 0.35, 0.6);
+const COLUMN_WIDTHS: [f32; 5] = [80.0, 280.0, 140.0, 160.0, 200.0];
+
+pub fn spawn_high_scores_menu(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    high_scores: Res<HighScores>
+) {
+    build_high_scores_menu(&mut commands, &game_assets, &high_scores);
+}
+
+pub fn despawn_high_scores_menu(
+    mut commands: Commands,
+    high_scores_menu_query: Query<Entity, With<HighScoresMenu>>
+) {
+    if let Ok(high_scores_menu_entity) = high_scores_menu_query.get_single() {
+        commands.entity(high_scores_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn build_high_scores_menu(
+    commands: &mut Commands,
+    game_assets: &Res<GameAssets>,
+    high_scores: &HighScores
+) -> Entity {
+    let high_scores_menu_entity = commands.spawn(
+        (NodeBundle {
+            style: MAIN_MENU_STYLE,
+            ..default()
+        },
+        HighScoresMenu {},
+    ))
+    .with_children(|parent| {
+        // === Title ===
+        parent.spawn(
+            NodeBundle {
+                style: TITLE_STYLE,
+                ..default()
+            }
+        ).with_children(|parent| {
+            parent.spawn(
+                TextBundle {
+                    text: Text {
+                        sections: vec![
+                            TextSection::new(
+                                "High Scores",
+                                get_title_text_style(game_assets)
+                            )
+                        ],
+                        alignment: TextAlignment::Center,
+                        ..default()
+                    },
+                    ..default()
+                }
+            );
+        });
+        // === Table ===
+        parent.spawn(
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                ..default()
+            }
+        ).with_children(|parent| {
+            spawn_row(parent, game_assets,
+                    ["#", "Name", "Score", "Difficulty", "Date"], Color::NONE);
+
+            if high_scores.scores.is_empty() {
+                parent.spawn(
+                    TextBundle::from_section(
+                        "No high scores yet",
+                        get_button_text_style(game_assets)
+                    )
+                );
+            }
+
+            high_scores.scores.iter()
+                .enumerate()
+                .for_each(|(index, entry)| {
+                    let background_color = if high_scores.is_latest(entry) {
+                        LATEST_ROW_COLOR
+                    } else {
+                        ROW_COLOR
+                    };
+
+                    spawn_row(parent, game_assets,
+                            entry_cells(index, entry), background_color);
+                });
+        });
+        // === Back Button ===
+        parent.spawn((
+            ButtonBundle {
+                style: BUTTON_STYLE,
+                background_color: NORMAL_BUTTON_COLOR.into(),
+                ..default()
+            },
+            BackButton {}
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+            TextBundle {
+                text: Text {
+                    sections: vec![
+                        TextSection::new(
+                            "Back",
+                            get_button_text_style(game_assets)
+                    )],
+                    alignment: TextAlignment::Center,
+                    ..default()
+                },
+                ..default()
+            });
+        });
+    })
+    .id();
+
+    high_scores_menu_entity
+}
+
+fn entry_cells(index: usize, entry: &HighScoreEntry) -> [String; 5] {
+    [
+        format!("{}", index + 1),
+        entry.name.clone(),
+        format!("{}", entry.score),
+        entry.difficulty.label().to_string(),
+        entry.date().unwrap_or_else(|| "-".to_string()),
+    ]
+}
+
+fn spawn_row<S: Into<String>>(
+    parent: &mut ChildBuilder,
+    game_assets: &Res<GameAssets>,
+    cells: [S; 5],
+    background_color: Color
+) {
+    parent.spawn(
+        NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                padding: UiRect::horizontal(Val::Px(8.0)),
+                ..default()
+            },
+            background_color: background_color.into(),
+            ..default()
+        }
+    ).with_children(|parent| {
+        cells.into_iter()
+            .zip(COLUMN_WIDTHS)
+            .for_each(|(cell, width)| {
+                parent.spawn(
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(width),
+                            ..default()
+                        },
+                        ..default()
+                    }
+                ).with_children(|parent| {
+                    parent.spawn(
+                        TextBundle::from_section(
+                            cell,
+                            get_button_text_style(game_assets)
+                        )
+                    );
+                });
+            });
+    });
+}
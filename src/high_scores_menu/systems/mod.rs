@@ -0,0 +1,3 @@
+
+pub mod interactions;
+pub mod layout;
\ No newline at end of file
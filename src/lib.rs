@@ -0,0 +1,32 @@
+
+// bevy queries and system params are verbose by nature
+#![allow(clippy::type_complexity, clippy::too_many_arguments)]
+
+use bevy::prelude::*;
+
+pub mod events;
+pub mod cli;
+pub mod controls;
+pub mod controls_menu;
+pub mod settings;
+pub mod settings_menu;
+pub mod storage;
+pub mod systems;
+pub mod game;
+pub mod gamepad;
+pub mod headless;
+pub mod loading;
+pub mod main_menu;
+pub mod music;
+pub mod high_scores_menu;
+
+#[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum AppState {
+    #[default]
+    Loading,
+    MainMenu,
+    Game,
+    GameOver,
+    HighScores,
+    Controls,
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct LoadingScreen {}
+
+#[derive(Component)]
+pub struct ProgressBar {}
+
+#[derive(Component)]
+pub struct LoadingText {}
@@ -0,0 +1,26 @@
+pub mod components;
+pub mod resources;
+mod systems;
+
+use bevy::prelude::*;
+
+use systems::*;
+use crate::AppState;
+
+// loads every asset up front behind a progress bar, so a missing
+// file is reported by name instead of drawing nothing later
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_systems(OnEnter(AppState::Loading), (
+                    start_loading,
+                    spawn_loading_screen
+        ))
+        .add_systems(Update,
+            check_loading.run_if(in_state(AppState::Loading))
+        )
+        .add_systems(OnExit(AppState::Loading), despawn_loading_screen);
+    }
+}
@@ -0,0 +1,116 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::events::SoundEffect;
+use crate::game::config::GameConfig;
+use crate::music::config::MusicConfig;
+
+// every file the game needs, all loaded before the main menu shows
+pub const FONT_PATH: &str = "fonts/FiraSans-Bold.ttf";
+pub const PLAYER_SPRITE_PATH: &str = "sprites/ball_blue_large.png";
+pub const ENEMY_SPRITE_PATH: &str = "sprites/ball_red_large.png";
+pub const STAR_SPRITE_PATH: &str = "sprites/star.png";
+pub const ENEMY_BOUNCE_SOUND_PATHS: [&str; 2] = ["audio/pluck_001.ogg", "audio/pluck_002.ogg"];
+pub const STAR_COLLECTED_SOUND_PATH: &str = "audio/laserLarge_000.ogg";
+pub const PLAYER_HIT_SOUND_PATH: &str = "audio/explosionCrunch_000.ogg";
+
+// handles to everything in the manifest, so no system spells out a path
+#[derive(Resource, Debug, Clone)]
+pub struct GameAssets {
+    pub font: Handle<Font>,
+    pub player_sprite: Handle<Image>,
+    pub enemy_sprite: Handle<Image>,
+    // by path, every sprite an enemy archetype asks for
+    pub enemy_sprites: HashMap<String, Handle<Image>>,
+    pub star_sprite: Handle<Image>,
+    pub enemy_bounce_sounds: Vec<Handle<AudioSource>>,
+    pub star_collected_sound: Handle<AudioSource>,
+    pub player_hit_sound: Handle<AudioSource>,
+    // by path, every track in the music playlists
+    pub music: HashMap<String, Handle<AudioSource>>,
+}
+
+// empty handles in every slot, for running without any assets
+impl Default for GameAssets {
+    fn default() -> Self {
+        GameAssets {
+            font: Handle::default(),
+            player_sprite: Handle::default(),
+            enemy_sprite: Handle::default(),
+            enemy_sprites: HashMap::default(),
+            star_sprite: Handle::default(),
+            enemy_bounce_sounds: vec![Handle::default(); ENEMY_BOUNCE_SOUND_PATHS.len()],
+            star_collected_sound: Handle::default(),
+            player_hit_sound: Handle::default(),
+            music: HashMap::default(),
+        }
+    }
+}
+
+impl GameAssets {
+    pub fn load(
+        asset_server: &AssetServer,
+        config: &GameConfig,
+        music_config: &MusicConfig
+    ) -> Self {
+        GameAssets {
+            font: asset_server.load(FONT_PATH),
+            player_sprite: asset_server.load(PLAYER_SPRITE_PATH),
+            enemy_sprite: asset_server.load(ENEMY_SPRITE_PATH),
+            enemy_sprites: config.enemy.archetypes.iter()
+                .map(|archetype| (archetype.sprite.clone(),
+                                asset_server.load(&archetype.sprite)))
+                .collect(),
+            star_sprite: asset_server.load(STAR_SPRITE_PATH),
+            enemy_bounce_sounds: ENEMY_BOUNCE_SOUND_PATHS.iter()
+                .map(|path| asset_server.load(*path))
+                .collect(),
+            star_collected_sound: asset_server.load(STAR_COLLECTED_SOUND_PATH),
+            player_hit_sound: asset_server.load(PLAYER_HIT_SOUND_PATH),
+            music: music_config.every_track()
+                .map(|track| (track.clone(), asset_server.load(track)))
+                .collect(),
+        }
+    }
+
+    // every handle with the path it was loaded from
+    pub fn manifest(&self) -> Vec<(String, UntypedHandle)> {
+        let mut manifest: Vec<(String, UntypedHandle)> = [
+            (FONT_PATH, self.font.clone().untyped()),
+            (PLAYER_SPRITE_PATH, self.player_sprite.clone().untyped()),
+            (ENEMY_SPRITE_PATH, self.enemy_sprite.clone().untyped()),
+            (STAR_SPRITE_PATH, self.star_sprite.clone().untyped()),
+            (STAR_COLLECTED_SOUND_PATH, self.star_collected_sound.clone().untyped()),
+            (PLAYER_HIT_SOUND_PATH, self.player_hit_sound.clone().untyped()),
+        ]
+        .into_iter()
+        .chain(ENEMY_BOUNCE_SOUND_PATHS.into_iter()
+            .zip(self.enemy_bounce_sounds.iter()
+                .map(|handle| handle.clone().untyped())))
+        .map(|(path, handle)| (path.to_string(), handle))
+        .collect();
+        manifest.extend(self.enemy_sprites.iter()
+            .map(|(path, handle)| (path.clone(), handle.clone().untyped())));
+        manifest.extend(self.music.iter()
+            .map(|(path, handle)| (path.clone(), handle.clone().untyped())));
+
+        manifest
+    }
+
+    // some sounds have variations, one is picked at random each time
+    pub fn sound_variants(&self, sound: SoundEffect) -> &[Handle<AudioSource>] {
+        match sound {
+            SoundEffect::EnemyBounce => &self.enemy_bounce_sounds,
+            // no sound of its own yet, the star pickup fits well enough
+            SoundEffect::StarCollected | SoundEffect::PowerUpCollected =>
+                std::slice::from_ref(&self.star_collected_sound),
+            SoundEffect::PlayerHit => std::slice::from_ref(&self.player_hit_sound),
+        }
+    }
+}
+
+// what the loading screen waits on, paired with the path for error messages
+#[derive(Resource, Debug, Default)]
+pub struct LoadingAssets {
+    pub assets: Vec<(String, UntypedHandle)>,
+    pub failed: Option<String>,
+}
@@ -0,0 +1,142 @@
+use bevy::{prelude::*, asset::LoadState};
+
+use crate::loading::{components::*, resources::*};
+use crate::game::replay::resources::ReplayPlayback;
+use crate::game::config::GameConfig;
+use crate::music::config::MusicConfig;
+use crate::AppState;
+
+const ERROR_COLOR: Color = Color::rgb(0.9, 0.25, 0.25);
+const BAR_BACKGROUND_COLOR: Color = Color::rgb(0.15, 0.15, 0.15);
+const BAR_COLOR: Color = Color::rgb(0.35, 0.75, 0.35);
+
+pub fn start_loading(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    config: Res<GameConfig>,
+    music_config: Res<MusicConfig>
+) {
+    let game_assets = GameAssets::load(&asset_server, &config, &music_config);
+
+    commands.insert_resource(LoadingAssets {
+        assets: game_assets.manifest(),
+        failed: None,
+    });
+    commands.insert_resource(game_assets);
+}
+
+pub fn spawn_loading_screen(mut commands: Commands) {
+    commands.spawn(
+        (NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            ..default()
+        },
+        LoadingScreen {},
+    ))
+    .with_children(|parent| {
+        // the game font is one of the things loading, so the built in one is used
+        parent.spawn((
+            TextBundle::from_section(
+                "Loading...",
+                TextStyle {
+                    font_size: 32.0,
+                    color: Color::WHITE,
+                    ..default()
+                }
+            ),
+            LoadingText {}
+        ));
+        parent.spawn(
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(400.0),
+                    height: Val::Px(24.0),
+                    ..default()
+                },
+                background_color: BAR_BACKGROUND_COLOR.into(),
+                ..default()
+            }
+        ).with_children(|parent| {
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    background_color: BAR_COLOR.into(),
+                    ..default()
+                },
+                ProgressBar {}
+            ));
+        });
+    });
+}
+
+pub fn despawn_loading_screen(
+    mut commands: Commands,
+    loading_screen_query: Query<Entity, With<LoadingScreen>>
+) {
+    if let Ok(loading_screen_entity) = loading_screen_query.get_single() {
+        commands.entity(loading_screen_entity).despawn_recursive();
+    }
+}
+
+pub fn check_loading(
+    asset_server: Res<AssetServer>,
+    mut loading_assets: ResMut<LoadingAssets>,
+    replay_playback: Option<Res<ReplayPlayback>>,
+    mut progress_bar_query: Query<&mut Style, With<ProgressBar>>,
+    mut loading_text_query: Query<(&mut Text, &mut Visibility), With<LoadingText>>,
+    mut next_app_state: ResMut<NextState<AppState>>
+) {
+    // stays on the error until the file is fixed and the game restarted
+    if loading_assets.failed.is_some() {
+        return;
+    }
+
+    let mut loaded = 0;
+    let mut failed = None;
+    loading_assets.assets.iter().for_each(|(path, handle)| {
+        match asset_server.get_load_state(handle.id()) {
+            Some(LoadState::Loaded) => loaded += 1,
+            Some(LoadState::Failed) => {
+                failed.get_or_insert_with(|| path.clone());
+            },
+            _ => {},
+        }
+    });
+
+    if let Some(path) = failed {
+        error!("Could not load asset {}, check the file exists in the assets folder", path);
+        if let Ok((mut text, _)) = loading_text_query.get_single_mut() {
+            text.sections[0].value = format!("Missing asset: {}", path);
+            text.sections[0].style.color = ERROR_COLOR;
+        }
+        loading_assets.failed = Some(path);
+        return;
+    }
+
+    let total = loading_assets.assets.len();
+    if let Ok(mut style) = progress_bar_query.get_single_mut() {
+        style.width = Val::Percent(loaded as f32 / total.max(1) as f32 * 100.0);
+    }
+
+    if loaded == total {
+        info!("Loaded {} assets", total);
+        // a replay goes straight to its run
+        next_app_state.set(if replay_playback.is_some() {
+            AppState::Game
+        } else {
+            AppState::MainMenu
+        });
+    }
+}
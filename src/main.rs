@@ -1,34 +1,113 @@
 
-use bevy::prelude::*;
+use bevy::{prelude::*, input::InputPlugin, log::LogPlugin};
 
-pub mod events;
-mod systems;
-mod game;
-mod main_menu;
-
-use systems::*;
-use game::GamePlugin;
-use main_menu::MainMenuPlugin;
+use ball_game::AppState;
+use ball_game::systems::*;
+use ball_game::cli::CliArgs;
+use ball_game::game::GamePlugin;
+use ball_game::game::rng::resources::FixedSeed;
+use ball_game::game::replay::resources::{Replay, ReplayPlayback};
+use ball_game::game::resources::Arena;
+use ball_game::game::score::resources::PendingHighScore;
+use ball_game::main_menu::MainMenuPlugin;
+use ball_game::high_scores_menu::HighScoresMenuPlugin;
+use ball_game::controls::{ControlsPlugin, resources::PendingRebind};
+use ball_game::controls_menu::ControlsMenuPlugin;
+use ball_game::settings::SettingsPlugin;
+use ball_game::settings_menu::SettingsMenuPlugin;
+use ball_game::music::MusicPlugin;
+use ball_game::loading::LoadingPlugin;
+use ball_game::headless::HeadlessPlugin;
+use ball_game::gamepad::GamepadPlugin;
 
 fn main() {
-    App::new()
-    .add_plugins((DefaultPlugins, GamePlugin, MainMenuPlugin))
+    let cli_args = CliArgs::parse();
+    let replay = cli_args.replay.as_ref().map(|path| {
+        Replay::load(path).unwrap_or_else(|error| {
+            eprintln!("Could not load replay {}: {}", path.display(), error);
+            std::process::exit(1);
+        })
+    });
+
+    let mut app = App::new();
+
+    if cli_args.headless {
+        // a replay is a single run
+        let runs = if replay.is_some() { 1 } else { cli_args.runs.unwrap_or(1) };
+
+        app
+        .add_plugins((
+            MinimalPlugins,
+            LogPlugin::default(),
+            InputPlugin,
+            ControlsPlugin,
+            GamePlugin::headless(),
+            HeadlessPlugin { runs }
+        ));
+    } else {
+        app
+        .add_plugins((
+            DefaultPlugins.set(WindowPlugin {
+                primary_window: Some(primary_window(replay.as_ref())),
+                ..default()
+            }),
+            ControlsPlugin,
+            SettingsPlugin,
+            GamePlugin::default(),
+            LoadingPlugin,
+            MainMenuPlugin,
+            HighScoresMenuPlugin,
+            ControlsMenuPlugin,
+            SettingsMenuPlugin,
+            MusicPlugin,
+            GamepadPlugin
+        ))
+        .add_systems(Startup,spawn_camera)
+        .add_systems(Update,(
+                    (
+                        exit_game,
+                        (
+                            transition_to_game_state,
+                            transition_to_main_menu_state
+                        )
+                        // nothing can be shown before the assets are in
+                        .run_if(not(in_state(AppState::Loading)))
+                    )
+                    // the name entry on the game over screen needs every key
+                    .run_if(not(resource_exists::<PendingHighScore>()))
+                    // a key being bound in the controls menu does nothing else
+                    .run_if(not(resource_exists::<PendingRebind>())),
+                    fit_camera_to_arena.run_if(resource_changed::<Arena>())
+        ));
+    }
+
+    app
+    .insert_resource(FixedSeed { seed: cli_args.seed })
     .add_state::<AppState>()
-    .add_systems(Startup,spawn_camera)
-    .add_systems(Update,(
-                exit_game,
-                handle_game_over,
-                transition_to_game_state,
-                transition_to_main_menu_state,
-                move_camera_when_window_resize
-    ))
-    .run();
+    .add_systems(Update, handle_game_over);
+
+    if let Some(difficulty) = cli_args.difficulty {
+        app.insert_resource(difficulty);
+    }
+
+    if let Some(replay) = replay {
+        app
+        .insert_resource(FixedSeed { seed: Some(replay.seed) })
+        .insert_resource(Arena::new(replay.arena))
+        .insert_resource(replay.difficulty)
+        .insert_resource(ReplayPlayback::new(replay));
+    }
+
+    app.run();
 }
 
-#[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
-pub enum AppState {
-    #[default]
-    MainMenu,
-    Game,
-    GameOver,
+// the replay's own arena is used, so any window size plays it back the same
+fn primary_window(replay: Option<&Replay>) -> Window {
+    match replay {
+        Some(_) => Window {
+            title: "Ball Game - Replay".to_string(),
+            ..default()
+        },
+        None => Window::default(),
+    }
 }
@@ -1,11 +1,27 @@
 
 use bevy::prelude::Component;
 
+use crate::game::difficulty::Difficulty;
+
 #[derive(Component)]
 pub struct MainMenu {}
 
 #[derive(Component)]
 pub struct PlayButton {}
 
+#[derive(Component)]
+pub struct DifficultyButton {
+    pub difficulty: Difficulty,
+}
+
+#[derive(Component)]
+pub struct HighScoresButton {}
+
+#[derive(Component)]
+pub struct ControlsButton {}
+
+#[derive(Component)]
+pub struct SettingsButton {}
+
 #[derive(Component)]
 pub struct QuitButton {}
\ No newline at end of file
@@ -1,5 +1,5 @@
 
-mod components;
+pub mod components;
 mod systems;
 pub mod styles;
 
@@ -19,6 +19,11 @@ impl Plugin for MainMenuPlugin {
                     spawn_main_menu)
         .add_systems(Update, (
                 interact_with_play_button,
+                interact_with_difficulty_buttons,
+                update_difficulty_buttons,
+                interact_with_high_scores_button,
+                interact_with_controls_button,
+                interact_with_settings_button,
                 interact_with_quit_button
             )
             .run_if(in_state(AppState::MainMenu))
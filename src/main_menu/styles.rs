@@ -1,29 +1,40 @@
 
 use bevy::prelude::*;
 
+use crate::loading::resources::GameAssets;
+
 pub const NORMAL_BUTTON_COLOR: Color = Color::rgb(0.15, 0.15, 0.15);
 pub const HOVERED_BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
 pub const PRESSED_BUTTON_COLOR: Color = Color::rgb(0.35, 0.75, 0.35);
 pub const TRANSPERNT_BUTTON_COLOR: Color = Color::rgba(0.15, 0.15, 0.15, 0.8);
+pub const SELECTED_BUTTON_COLOR: Color = Color::rgb(0.2, 0.45, 0.2);
 
 pub const MAIN_MENU_STYLE: Style = main_menu_style();
 pub const BUTTON_STYLE: Style = button_style();
 pub const IMAGE_STYLE: Style = image_style();
 pub const TITLE_STYLE: Style = title_style();
+pub const OPTIONS_ROW_STYLE: Style = options_row_style();
+pub const SMALL_BUTTON_STYLE: Style = small_button_style();
 
-pub fn get_button_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+pub fn get_button_text_style(game_assets: &Res<GameAssets>) -> TextStyle {
     TextStyle {
-        font: asset_server
-                .load("fonts/FiraSans-Bold.ttf"),
+        font: game_assets.font.clone(),
         font_size: 32.0,
         color: Color::WHITE
     }
 }
 
-pub fn get_title_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+pub fn get_small_button_text_style(game_assets: &Res<GameAssets>) -> TextStyle {
+    TextStyle {
+        font: game_assets.font.clone(),
+        font_size: 24.0,
+        color: Color::WHITE
+    }
+}
+
+pub fn get_title_text_style(game_assets: &Res<GameAssets>) -> TextStyle {
     TextStyle {
-        font: asset_server
-                .load("fonts/FiraSans-Bold.ttf"),
+        font: game_assets.font.clone(),
         font_size: 64.0,
         color: Color::WHITE
     }
@@ -53,6 +64,26 @@ const fn button_style() -> Style {
     style
 }
 
+const fn options_row_style() -> Style {
+    let mut style = Style::DEFAULT;
+    style.flex_direction = FlexDirection::Row;
+    style.justify_content = JustifyContent::Center;
+    style.align_items = AlignItems::Center;
+    style.column_gap = Val::Px(8.0);
+
+    style
+}
+
+const fn small_button_style() -> Style {
+    let mut style = Style::DEFAULT;
+    style.width = Val::Px(140.0);
+    style.height = Val::Px(50.0);
+    style.justify_content = JustifyContent::Center;
+    style.align_items = AlignItems::Center;
+
+    style
+}
+
 const fn image_style() -> Style {
     let mut style = Style::DEFAULT;
     style.width = Val::Px(64.0);
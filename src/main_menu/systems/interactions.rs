@@ -2,7 +2,13 @@
 use bevy::{prelude::*, app::AppExit};
 
 use crate::{
-    main_menu::{styles::*, components::*}, 
+    main_menu::{
+        styles::*,
+        components::*,
+        systems::layout::difficulty_button_color
+    },
+    game::difficulty::Difficulty,
+    settings::resources::SettingsMenuOpen,
     AppState
 };
 
@@ -24,6 +30,92 @@ pub fn interact_with_play_button(
     }
 }
 
+pub fn interact_with_difficulty_buttons(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor, &DifficultyButton), 
+                        Changed<Interaction>>,
+    mut difficulty: ResMut<Difficulty>
+) {
+    button_query.for_each_mut(|(interaction, mut background_color, button)| {
+        *background_color = match *interaction {
+            Interaction::Pressed => {
+                *difficulty = button.difficulty;
+                PRESSED_BUTTON_COLOR.into()
+            },
+            Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+            Interaction::None =>
+                difficulty_button_color(button.difficulty, *difficulty).into(),
+        }
+    });
+}
+
+pub fn update_difficulty_buttons(
+    difficulty: Res<Difficulty>,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor, &DifficultyButton)>
+) {
+    if difficulty.is_changed() {
+        button_query.for_each_mut(|(interaction, mut background_color, button)| {
+            if *interaction == Interaction::None {
+                *background_color =
+                    difficulty_button_color(button.difficulty, *difficulty).into();
+            }
+        });
+    }
+}
+
+pub fn interact_with_high_scores_button(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), 
+                        (Changed<Interaction>, With<HighScoresButton>)>,
+    mut next_app_state: ResMut<NextState<AppState>>
+) {
+    if let Ok((interaction, mut background_color)) = 
+                button_query.get_single_mut() {
+                    *background_color = match *interaction {
+                    Interaction::Pressed => {
+                        next_app_state.set(AppState::HighScores);
+                        PRESSED_BUTTON_COLOR.into()
+                    },
+                    Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+                    Interaction::None => NORMAL_BUTTON_COLOR.into(),
+                }
+    }
+}
+
+pub fn interact_with_controls_button(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), 
+                        (Changed<Interaction>, With<ControlsButton>)>,
+    mut next_app_state: ResMut<NextState<AppState>>
+) {
+    if let Ok((interaction, mut background_color)) = 
+                button_query.get_single_mut() {
+                    *background_color = match *interaction {
+                    Interaction::Pressed => {
+                        next_app_state.set(AppState::Controls);
+                        PRESSED_BUTTON_COLOR.into()
+                    },
+                    Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+                    Interaction::None => NORMAL_BUTTON_COLOR.into(),
+                }
+    }
+}
+
+pub fn interact_with_settings_button(
+    mut commands: Commands,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), 
+                        (Changed<Interaction>, With<SettingsButton>)>
+) {
+    if let Ok((interaction, mut background_color)) = 
+                button_query.get_single_mut() {
+                    *background_color = match *interaction {
+                    Interaction::Pressed => {
+                        commands.init_resource::<SettingsMenuOpen>();
+                        PRESSED_BUTTON_COLOR.into()
+                    },
+                    Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+                    Interaction::None => NORMAL_BUTTON_COLOR.into(),
+                }
+    }
+}
+
 pub fn interact_with_quit_button(
     mut app_exit_evet_writer: EventWriter<AppExit>,
     mut button_query: Query<(&Interaction, &mut BackgroundColor), 
@@ -2,12 +2,15 @@
 use bevy::prelude::*;
 
 use crate::main_menu::{components::*, styles::*};
+use crate::game::difficulty::Difficulty;
+use crate::loading::resources::GameAssets;
 
 pub fn spawn_main_menu(
     mut commands: Commands,
-    asset_server: Res<AssetServer>
+    game_assets: Res<GameAssets>,
+    difficulty: Res<Difficulty>
 ) {
-    build_main_menu(&mut commands, &asset_server);
+    build_main_menu(&mut commands, &game_assets, *difficulty);
 }
 
 pub fn despawn_main_menu(
@@ -21,7 +24,8 @@ pub fn despawn_main_menu(
 
 pub fn build_main_menu(
     commands: &mut Commands,
-    asset_server: &Res<AssetServer>
+    game_assets: &Res<GameAssets>,
+    selected_difficulty: Difficulty
 ) -> Entity {
     let main_menu_entity = commands.spawn(
         (NodeBundle {
@@ -42,8 +46,7 @@ pub fn build_main_menu(
             parent.spawn(
                 ImageBundle {
                     style: IMAGE_STYLE,
-                    image: asset_server
-                            .load("sprites/ball_blue_large.png")
+                    image: game_assets.player_sprite.clone()
                             .into(),
                     ..default()
                 }
@@ -55,7 +58,7 @@ pub fn build_main_menu(
                         sections: vec![
                             TextSection::new(
                                 "Bevy Ball Game",
-                                get_title_text_style(asset_server)
+                                get_title_text_style(game_assets)
                             )
                         ],
                         alignment: TextAlignment::Center,
@@ -69,8 +72,7 @@ pub fn build_main_menu(
             parent.spawn(
                 ImageBundle {
                     style: IMAGE_STYLE,
-                    image: asset_server
-                            .load("sprites/ball_red_large.png")
+                    image: game_assets.enemy_sprite.clone()
                             .into(),
                     ..default()
                 }
@@ -92,7 +94,113 @@ pub fn build_main_menu(
                     sections: vec![
                         TextSection::new(
                             "Play", 
-                            get_button_text_style(asset_server)
+                            get_button_text_style(game_assets)
+                    )],
+                    alignment: TextAlignment::Center,
+                    ..default()
+                },
+                ..default()
+            });
+        });
+        // === Difficulty Buttons ===
+        parent.spawn(
+            NodeBundle {
+                style: OPTIONS_ROW_STYLE,
+                ..default()
+            }
+        ).with_children(|parent| {
+            Difficulty::ALL.iter().for_each(|difficulty| {
+                parent.spawn((
+                    ButtonBundle {
+                        style: SMALL_BUTTON_STYLE,
+                        background_color: difficulty_button_color(
+                            *difficulty, selected_difficulty).into(),
+                        ..default()
+                    },
+                    DifficultyButton { difficulty: *difficulty }
+                ))
+                .with_children(|parent| {
+                    parent.spawn(
+                    TextBundle {
+                        text: Text {
+                            sections: vec![
+                                TextSection::new(
+                                    difficulty.label(),
+                                    get_small_button_text_style(game_assets)
+                            )],
+                            alignment: TextAlignment::Center,
+                            ..default()
+                        },
+                        ..default()
+                    });
+                });
+            });
+        });
+        // === High Scores Button ===
+        parent.spawn((
+            ButtonBundle {
+                style: BUTTON_STYLE,
+                background_color: NORMAL_BUTTON_COLOR.into(),
+                ..default()
+            },
+            HighScoresButton {}
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+            TextBundle {
+                text: Text {
+                    sections: vec![
+                        TextSection::new(
+                            "High Scores", 
+                            get_button_text_style(game_assets)
+                    )],
+                    alignment: TextAlignment::Center,
+                    ..default()
+                },
+                ..default()
+            });
+        });
+        // === Controls Button ===
+        parent.spawn((
+            ButtonBundle {
+                style: BUTTON_STYLE,
+                background_color: NORMAL_BUTTON_COLOR.into(),
+                ..default()
+            },
+            ControlsButton {}
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+            TextBundle {
+                text: Text {
+                    sections: vec![
+                        TextSection::new(
+                            "Controls", 
+                            get_button_text_style(game_assets)
+                    )],
+                    alignment: TextAlignment::Center,
+                    ..default()
+                },
+                ..default()
+            });
+        });
+        // === Settings Button ===
+        parent.spawn((
+            ButtonBundle {
+                style: BUTTON_STYLE,
+                background_color: NORMAL_BUTTON_COLOR.into(),
+                ..default()
+            },
+            SettingsButton {}
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+            TextBundle {
+                text: Text {
+                    sections: vec![
+                        TextSection::new(
+                            "Settings", 
+                            get_button_text_style(game_assets)
                     )],
                     alignment: TextAlignment::Center,
                     ..default()
@@ -116,7 +224,7 @@ pub fn build_main_menu(
                     sections: vec![
                         TextSection::new(
                             "Quit", 
-                            get_button_text_style(asset_server)
+                            get_button_text_style(game_assets)
                     )],
                     alignment: TextAlignment::Center,
                     ..default()
@@ -128,4 +236,15 @@ pub fn build_main_menu(
     .id();
 
     main_menu_entity
+}
+
+pub fn difficulty_button_color(
+    difficulty: Difficulty,
+    selected_difficulty: Difficulty
+) -> Color {
+    if difficulty == selected_difficulty {
+        SELECTED_BUTTON_COLOR
+    } else {
+        NORMAL_BUTTON_COLOR
+    }
 }
\ No newline at end of file
@@ -0,0 +1,12 @@
+use bevy::prelude::Component;
+
+use crate::music::resources::Playlist;
+
+#[derive(Component)]
+pub struct MusicTrack {
+    pub playlist: Playlist,
+    pub index: usize,
+    // the crossfade, from silent to full
+    pub fade: f32,
+    pub fading_out: bool,
+}
@@ -0,0 +1,130 @@
+use std::{fmt, path::PathBuf};
+
+use bevy::{prelude::Resource, log::{error, info}};
+use serde::Deserialize;
+
+use crate::storage::{self, StorageError};
+use crate::music::resources::Playlist;
+
+pub const MUSIC_CONFIG_PATH: &str = "config/music.ron";
+
+#[derive(Resource, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MusicConfig {
+    pub crossfade_seconds: f32,
+    // how loud the music stays while the game is paused
+    pub paused_volume: f32,
+    pub playlists: Playlists,
+}
+
+// paths are relative to the assets folder
+#[derive(Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Playlists {
+    pub main_menu: Vec<String>,
+    pub game: Vec<String>,
+    pub game_over: Vec<String>,
+}
+
+impl Default for MusicConfig {
+    fn default() -> Self {
+        MusicConfig {
+            crossfade_seconds: 1.5,
+            paused_volume: 0.3,
+            playlists: Playlists::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum MusicConfigError {
+    Read(StorageError),
+    Invalid(Vec<String>),
+}
+
+impl fmt::Display for MusicConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MusicConfigError::Read(error) => write!(f, "{}", error),
+            MusicConfigError::Invalid(problems) =>
+                write!(f, "invalid values:\n  {}", problems.join("\n  ")),
+        }
+    }
+}
+
+impl MusicConfig {
+    // no config means no music, the game plays on either way
+    pub fn load() -> Self {
+        let path = asset_path(MUSIC_CONFIG_PATH);
+
+        match MusicConfig::load_from(&path) {
+            Ok(config) => {
+                info!("Loaded music config from {}", path.display());
+                config
+            },
+            Err(error) => {
+                error!("Could not use music config {}: {}\nPlaying no music",
+                        path.display(), error);
+                MusicConfig::default()
+            }
+        }
+    }
+
+    pub fn load_from(path: &std::path::Path) -> Result<Self, MusicConfigError> {
+        let config: MusicConfig = storage::load_ron(path)
+            .map_err(MusicConfigError::Read)?;
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), MusicConfigError> {
+        let mut problems = Vec::new();
+
+        if !(self.crossfade_seconds.is_finite() && self.crossfade_seconds >= 0.0) {
+            problems.push(format!("crossfade_seconds must be zero or more, got {}",
+                                self.crossfade_seconds));
+        }
+        if !(0.0..=1.0).contains(&self.paused_volume) {
+            problems.push(format!("paused_volume must be between 0 and 1, got {}",
+                                self.paused_volume));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(MusicConfigError::Invalid(problems))
+        }
+    }
+
+    pub fn tracks(&self, playlist: Playlist) -> &[String] {
+        match playlist {
+            Playlist::MainMenu => &self.playlists.main_menu,
+            Playlist::Game => &self.playlists.game,
+            Playlist::GameOver => &self.playlists.game_over,
+        }
+    }
+
+    // every playlist's tracks, loaded up front with the rest of the assets
+    pub fn every_track(&self) -> impl Iterator<Item = &String> {
+        let playlists = &self.playlists;
+
+        playlists.main_menu.iter()
+            .chain(&playlists.game)
+            .chain(&playlists.game_over)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn asset_path(path: &str) -> PathBuf {
+    bevy::asset::io::file::FileAssetReader::get_base_path()
+        .join("assets")
+        .join(path)
+}
+
+// there is no file system on the web, the defaults are used there
+#[cfg(target_arch = "wasm32")]
+fn asset_path(path: &str) -> PathBuf {
+    PathBuf::from(path)
+}
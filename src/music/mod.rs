@@ -0,0 +1,27 @@
+pub mod components;
+pub mod config;
+pub mod resources;
+mod systems;
+
+use bevy::prelude::*;
+
+use systems::*;
+use resources::*;
+use config::MusicConfig;
+
+// background music for each part of the game, from the playlists
+// in `assets/config/music.ron`
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .insert_resource(MusicConfig::load())
+        .init_resource::<MusicPlayer>()
+        .add_systems(Update, (
+            switch_playlist,
+            advance_playlist,
+            fade_music
+        ).chain());
+    }
+}
@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Playlist {
+    MainMenu,
+    Game,
+    GameOver,
+}
+
+impl Playlist {
+    // the screens reached from the main menu keep its music going
+    pub fn for_state(app_state: AppState) -> Playlist {
+        match app_state {
+            AppState::Game => Playlist::Game,
+            AppState::GameOver => Playlist::GameOver,
+            AppState::Loading |
+            AppState::MainMenu |
+            AppState::HighScores |
+            AppState::Controls => Playlist::MainMenu,
+        }
+    }
+}
+
+#[derive(Resource, Debug)]
+pub struct MusicPlayer {
+    pub playlist: Option<Playlist>,
+    // eases down to the paused volume and back
+    pub duck: f32,
+}
+
+impl Default for MusicPlayer {
+    fn default() -> Self {
+        MusicPlayer {
+            playlist: None,
+            duck: 1.0,
+        }
+    }
+}
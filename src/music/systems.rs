@@ -0,0 +1,119 @@
+use bevy::{prelude::*, audio::Volume};
+
+use crate::music::{components::*, config::MusicConfig, resources::*};
+use crate::game::SimulationState;
+use crate::loading::resources::GameAssets;
+use crate::settings::resources::{Settings, VolumeBus};
+use crate::AppState;
+
+fn spawn_track(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    config: &MusicConfig,
+    playlist: Playlist,
+    index: usize
+) {
+    let Some(path) = config.tracks(playlist).get(index) else {
+        return;
+    };
+
+    commands.spawn((
+        AudioBundle {
+            // every track was loaded behind the loading screen
+            source: game_assets.music.get(path).cloned().unwrap_or_default(),
+            // starts silent, the fade brings it in
+            settings: PlaybackSettings::ONCE
+                .with_volume(Volume::new_relative(0.0)),
+        },
+        MusicTrack {
+            playlist,
+            index,
+            fade: 0.0,
+            fading_out: false,
+        }
+    ));
+}
+
+pub fn switch_playlist(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    config: Res<MusicConfig>,
+    app_state: Res<State<AppState>>,
+    mut music_player: ResMut<MusicPlayer>,
+    mut track_query: Query<&mut MusicTrack>
+) {
+    let playlist = Playlist::for_state(*app_state.get());
+    if music_player.playlist == Some(playlist) {
+        return;
+    }
+
+    track_query.for_each_mut(|mut track| track.fading_out = true);
+    spawn_track(&mut commands, &game_assets, &config, playlist, 0);
+    music_player.playlist = Some(playlist);
+}
+
+// a finished track makes way for the next one, the last goes back to the first
+pub fn advance_playlist(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    config: Res<MusicConfig>,
+    track_query: Query<(Entity, &MusicTrack, &AudioSink)>
+) {
+    track_query.for_each(|(entity, track, sink)| {
+        if track.fading_out || !sink.empty() {
+            return;
+        }
+
+        commands.entity(entity).despawn();
+        let next = (track.index + 1) % config.tracks(track.playlist).len().max(1);
+        spawn_track(&mut commands, &game_assets, &config, track.playlist, next);
+    });
+}
+
+pub fn fade_music(
+    mut commands: Commands,
+    config: Res<MusicConfig>,
+    settings: Res<Settings>,
+    app_state: Res<State<AppState>>,
+    simulation_state: Res<State<SimulationState>>,
+    time: Res<Time>,
+    mut music_player: ResMut<MusicPlayer>,
+    mut track_query: Query<(Entity, &mut MusicTrack, Option<&AudioSink>)>
+) {
+    // a crossfade of zero switches straight away
+    let step = if config.crossfade_seconds > 0.0 {
+        time.delta_seconds() / config.crossfade_seconds
+    } else {
+        1.0
+    };
+
+    let paused = *app_state.get() == AppState::Game &&
+                *simulation_state.get() == SimulationState::Paused;
+    let duck = if paused { config.paused_volume } else { 1.0 };
+    music_player.duck = move_towards(music_player.duck, duck, step);
+
+    let volume = settings.output_volume(VolumeBus::Music) * music_player.duck;
+
+    track_query.for_each_mut(|(entity, mut track, sink)| {
+        let target = if track.fading_out { 0.0 } else { 1.0 };
+        track.fade = move_towards(track.fade, target, step);
+
+        if track.fading_out && track.fade <= 0.0 {
+            commands.entity(entity).despawn();
+            return;
+        }
+
+        // the sink shows up once the track has loaded
+        if let Some(sink) = sink {
+            sink.set_volume(track.fade * volume);
+        }
+    });
+}
+
+fn move_towards(value: f32, target: f32, step: f32) -> f32 {
+    if value < target {
+        (value + step).min(target)
+    } else {
+        (value - step).max(target)
+    }
+}
@@ -0,0 +1,21 @@
+pub mod resources;
+mod systems;
+
+use bevy::prelude::*;
+
+use systems::*;
+use resources::*;
+
+// the player's audio, video and gameplay preferences,
+// applied whenever they change
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .insert_resource(Settings::load())
+        .add_systems(Update,
+            apply_window_settings.run_if(resource_changed::<Settings>())
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use bevy::{prelude::*, log::warn};
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{self, StorageError};
+
+pub const SETTINGS_FILE_NAME: &str = "settings.ron";
+pub const SETTINGS_FILE_VERSION: u32 = 1;
+pub const RESOLUTIONS: [UVec2; 4] = [
+    UVec2::new(1280, 720),
+    UVec2::new(1600, 900),
+    UVec2::new(1920, 1080),
+    UVec2::new(2560, 1440),
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeBus {
+    Master,
+    Sfx,
+    Music,
+}
+
+impl VolumeBus {
+    pub const ALL: [VolumeBus; 3] = [
+        VolumeBus::Master,
+        VolumeBus::Sfx,
+        VolumeBus::Music,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            VolumeBus::Master => "Master Volume",
+            VolumeBus::Sfx => "Effects Volume",
+            VolumeBus::Music => "Music Volume",
+        }
+    }
+}
+
+// the player's preferences, kept apart from the game config
+// which is tuning and ships with the game
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    pub master_volume: f32,
+    pub sfx_volume: f32,
+    pub music_volume: f32,
+    pub master_muted: bool,
+    pub sfx_muted: bool,
+    pub music_muted: bool,
+    pub fullscreen: bool,
+    // the window size when not fullscreen
+    pub resolution: UVec2,
+    pub vsync: bool,
+    pub pause_on_focus_loss: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            master_volume: 1.0,
+            sfx_volume: 0.8,
+            music_volume: 0.6,
+            master_muted: false,
+            sfx_muted: false,
+            music_muted: false,
+            fullscreen: false,
+            resolution: RESOLUTIONS[0],
+            vsync: true,
+            pause_on_focus_loss: true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SettingsFile {
+    version: u32,
+    settings: Settings,
+}
+
+// read on its own first so a future format is reported, not misparsed
+#[derive(Deserialize)]
+struct FileVersion {
+    version: u32,
+}
+
+impl Settings {
+    pub fn volume(&self, bus: VolumeBus) -> f32 {
+        match bus {
+            VolumeBus::Master => self.master_volume,
+            VolumeBus::Sfx => self.sfx_volume,
+            VolumeBus::Music => self.music_volume,
+        }
+    }
+
+    pub fn set_volume(&mut self, bus: VolumeBus, volume: f32) {
+        let volume = volume.clamp(0.0, 1.0);
+
+        match bus {
+            VolumeBus::Master => self.master_volume = volume,
+            VolumeBus::Sfx => self.sfx_volume = volume,
+            VolumeBus::Music => self.music_volume = volume,
+        }
+    }
+
+    pub fn is_muted(&self, bus: VolumeBus) -> bool {
+        match bus {
+            VolumeBus::Master => self.master_muted,
+            VolumeBus::Sfx => self.sfx_muted,
+            VolumeBus::Music => self.music_muted,
+        }
+    }
+
+    pub fn toggle_mute(&mut self, bus: VolumeBus) {
+        match bus {
+            VolumeBus::Master => self.master_muted = !self.master_muted,
+            VolumeBus::Sfx => self.sfx_muted = !self.sfx_muted,
+            VolumeBus::Music => self.music_muted = !self.music_muted,
+        }
+    }
+
+    // what a sound on the bus plays at, with the master volume
+    // and both mutes applied
+    pub fn output_volume(&self, bus: VolumeBus) -> f32 {
+        if self.master_muted || self.is_muted(bus) {
+            return 0.0;
+        }
+
+        match bus {
+            VolumeBus::Master => self.master_volume,
+            bus => self.master_volume * self.volume(bus),
+        }
+    }
+
+    // the next size in the list, wrapping around
+    pub fn next_resolution(&self) -> UVec2 {
+        RESOLUTIONS.iter()
+            .position(|resolution| *resolution == self.resolution)
+            .map(|index| RESOLUTIONS[(index + 1) % RESOLUTIONS.len()])
+            .unwrap_or(RESOLUTIONS[0])
+    }
+
+    pub fn load() -> Self {
+        let Some(path) = storage::data_file(SETTINGS_FILE_NAME) else {
+            return Settings::default();
+        };
+
+        match Settings::load_from(&path) {
+            Ok(settings) => settings,
+            Err(StorageError::NotFound) => Settings::default(),
+            Err(error) => {
+                warn!("Could not read settings from {}: {}",
+                        path.display(), error);
+                if let Some(backup_path) = storage::back_up_file(&path) {
+                    warn!("Moved unreadable settings to {}",
+                            backup_path.display());
+                }
+
+                Settings::default()
+            }
+        }
+    }
+
+    pub fn load_from(path: &std::path::Path) -> Result<Self, StorageError> {
+        read_settings_file(path).map(Settings::sanitized)
+    }
+
+    // a hand edited file is pulled back into range rather than thrown away
+    fn sanitized(mut self) -> Self {
+        let defaults = Settings::default();
+
+        VolumeBus::ALL.iter().for_each(|bus| {
+            let volume = self.volume(*bus);
+            let volume = if volume.is_finite() {
+                volume
+            } else {
+                defaults.volume(*bus)
+            };
+            self.set_volume(*bus, volume);
+        });
+
+        if !RESOLUTIONS.contains(&self.resolution) {
+            self.resolution = RESOLUTIONS[0];
+        }
+
+        self
+    }
+
+    pub fn save(&self) {
+        let Some(path) = storage::data_file(SETTINGS_FILE_NAME) else {
+            return;
+        };
+
+        let file = SettingsFile {
+            version: SETTINGS_FILE_VERSION,
+            settings: self.clone(),
+        };
+
+        if let Err(error) = storage::save_ron(&path, &file) {
+            warn!("Could not save settings to {}: {}",
+                    path.display(), error);
+        }
+    }
+}
+
+fn read_settings_file(
+    path: &std::path::Path
+) -> Result<Settings, StorageError> {
+    let file_version: FileVersion = storage::load_ron(path)?;
+    if file_version.version != SETTINGS_FILE_VERSION {
+        return Err(StorageError::UnsupportedVersion(file_version.version));
+    }
+
+    let file: SettingsFile = storage::load_ron(path)?;
+
+    Ok(file.settings)
+}
+
+// set while the settings panel is open, over the main or pause menu
+#[derive(Resource, Debug, Default)]
+pub struct SettingsMenuOpen;
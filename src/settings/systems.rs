@@ -0,0 +1,38 @@
+use bevy::{prelude::*, window::{PresentMode, PrimaryWindow, WindowMode}};
+
+use crate::settings::resources::*;
+
+pub fn apply_window_settings(
+    settings: Res<Settings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>
+) {
+    let Ok(mut window) = window_query.get_single_mut() else {
+        return;
+    };
+
+    let present_mode = if settings.vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+    // only written when different, a volume drag changes the settings every frame
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+
+    let mode = if settings.fullscreen {
+        WindowMode::BorderlessFullscreen
+    } else {
+        WindowMode::Windowed
+    };
+    if window.mode != mode {
+        window.mode = mode;
+    }
+
+    let resolution = settings.resolution.as_vec2();
+    if !settings.fullscreen &&
+        (window.resolution.width() != resolution.x ||
+        window.resolution.height() != resolution.y) {
+            window.resolution.set(resolution.x, resolution.y);
+    }
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::Component;
+
+use crate::settings::resources::VolumeBus;
+
+#[derive(Component)]
+pub struct SettingsMenu {}
+
+// the track, clicking or dragging on it sets the volume
+#[derive(Component)]
+pub struct VolumeSlider {
+    pub bus: VolumeBus,
+}
+
+#[derive(Component)]
+pub struct VolumeFill {
+    pub bus: VolumeBus,
+}
+
+#[derive(Component)]
+pub struct VolumeText {
+    pub bus: VolumeBus,
+}
+
+// the - and + buttons, for the keyboard and gamepad
+#[derive(Component)]
+pub struct VolumeStepButton {
+    pub bus: VolumeBus,
+    pub step: f32,
+}
+
+#[derive(Component)]
+pub struct MuteButton {
+    pub bus: VolumeBus,
+}
+
+#[derive(Component)]
+pub struct MuteLabel {
+    pub bus: VolumeBus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingOption {
+    Fullscreen,
+    Resolution,
+    Vsync,
+    PauseOnFocusLoss,
+}
+
+#[derive(Component)]
+pub struct SettingButton {
+    pub option: SettingOption,
+}
+
+#[derive(Component)]
+pub struct SettingLabel {
+    pub option: SettingOption,
+}
+
+#[derive(Component)]
+pub struct BackButton {}
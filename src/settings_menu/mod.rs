@@ -0,0 +1,39 @@
+pub mod components;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::game::SimulationState;
+use crate::settings::resources::SettingsMenuOpen;
+use systems::layout::*;
+use systems::interactions::*;
+
+// a panel on top of the main or pause menu, so the game
+// underneath keeps its state while the settings are open
+pub struct SettingsMenuPlugin;
+
+impl Plugin for SettingsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_systems(Update, (
+                spawn_settings_menu.run_if(resource_added::<SettingsMenuOpen>()),
+                (
+                    drag_volume_sliders,
+                    interact_with_volume_step_buttons,
+                    interact_with_mute_buttons,
+                    interact_with_setting_buttons,
+                    interact_with_back_button,
+                    update_volume_sliders,
+                    update_setting_labels
+                )
+                .chain()
+                .run_if(resource_exists::<SettingsMenuOpen>()),
+                despawn_settings_menu.run_if(resource_removed::<SettingsMenuOpen>()),
+                save_changed_settings
+            )
+        )
+        .add_systems(OnExit(AppState::MainMenu), close_settings_menu)
+        .add_systems(OnExit(SimulationState::Paused), close_settings_menu);
+    }
+}
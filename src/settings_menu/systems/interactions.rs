@@ -0,0 +1,165 @@
+use bevy::{prelude::*, ui::RelativeCursorPosition};
+
+use crate::{
+    main_menu::styles::*,
+    settings_menu::components::*,
+    settings::resources::*,
+};
+use super::layout::{mute_text, setting_text, volume_text};
+
+// follows the mouse for as long as the button is held
+pub fn drag_volume_sliders(
+    slider_query: Query<(&Interaction, &RelativeCursorPosition, &VolumeSlider)>,
+    mut settings: ResMut<Settings>
+) {
+    slider_query.for_each(|(interaction, cursor_position, slider)| {
+        if *interaction != Interaction::Pressed {
+            return;
+        }
+        let Some(normalized) = cursor_position.normalized else {
+            return;
+        };
+
+        let volume = normalized.x.clamp(0.0, 1.0);
+        if settings.volume(slider.bus) != volume {
+            settings.set_volume(slider.bus, volume);
+        }
+    });
+}
+
+pub fn interact_with_volume_step_buttons(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor, &VolumeStepButton),
+                        Changed<Interaction>>,
+    mut settings: ResMut<Settings>
+) {
+    button_query.for_each_mut(|(interaction, mut background_color, button)| {
+        *background_color = match *interaction {
+            Interaction::Pressed => {
+                // rounded so repeated steps land on whole tens
+                let volume = settings.volume(button.bus) + button.step;
+                settings.set_volume(button.bus, (volume * 10.0).round() / 10.0);
+                PRESSED_BUTTON_COLOR.into()
+            },
+            Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => NORMAL_BUTTON_COLOR.into(),
+        }
+    });
+}
+
+pub fn interact_with_mute_buttons(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor, &MuteButton),
+                        Changed<Interaction>>,
+    mut settings: ResMut<Settings>
+) {
+    button_query.for_each_mut(|(interaction, mut background_color, button)| {
+        *background_color = match *interaction {
+            Interaction::Pressed => {
+                settings.toggle_mute(button.bus);
+                PRESSED_BUTTON_COLOR.into()
+            },
+            Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => NORMAL_BUTTON_COLOR.into(),
+        }
+    });
+}
+
+pub fn interact_with_setting_buttons(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor, &SettingButton),
+                        Changed<Interaction>>,
+    mut settings: ResMut<Settings>
+) {
+    button_query.for_each_mut(|(interaction, mut background_color, button)| {
+        *background_color = match *interaction {
+            Interaction::Pressed => {
+                match button.option {
+                    SettingOption::Fullscreen =>
+                        settings.fullscreen = !settings.fullscreen,
+                    SettingOption::Resolution =>
+                        settings.resolution = settings.next_resolution(),
+                    SettingOption::Vsync =>
+                        settings.vsync = !settings.vsync,
+                    SettingOption::PauseOnFocusLoss =>
+                        settings.pause_on_focus_loss = !settings.pause_on_focus_loss,
+                }
+                PRESSED_BUTTON_COLOR.into()
+            },
+            Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => NORMAL_BUTTON_COLOR.into(),
+        }
+    });
+}
+
+pub fn interact_with_back_button(
+    mut commands: Commands,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor),
+                        (Changed<Interaction>, With<BackButton>)>
+) {
+    if let Ok((interaction, mut background_color)) =
+                button_query.get_single_mut() {
+                    *background_color = match *interaction {
+                    Interaction::Pressed => {
+                        commands.remove_resource::<SettingsMenuOpen>();
+                        PRESSED_BUTTON_COLOR.into()
+                    },
+                    Interaction::Hovered => HOVERED_BUTTON_COLOR.into(),
+                    Interaction::None => NORMAL_BUTTON_COLOR.into(),
+                }
+    }
+}
+
+pub fn update_volume_sliders(
+    settings: Res<Settings>,
+    mut fill_query: Query<(&mut Style, &VolumeFill)>,
+    mut text_query: Query<(&mut Text, &VolumeText), Without<MuteLabel>>,
+    mut mute_label_query: Query<(&mut Text, &MuteLabel), Without<VolumeText>>
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    fill_query.for_each_mut(|(mut style, fill)| {
+        style.width = Val::Percent(settings.volume(fill.bus) * 100.0);
+    });
+    text_query.for_each_mut(|(mut text, volume_text_marker)| {
+        text.sections[0].value = volume_text(settings.volume(volume_text_marker.bus));
+    });
+    mute_label_query.for_each_mut(|(mut text, label)| {
+        text.sections[0].value = mute_text(settings.is_muted(label.bus)).to_string();
+    });
+}
+
+pub fn update_setting_labels(
+    settings: Res<Settings>,
+    mut label_query: Query<(&mut Text, &SettingLabel)>
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    label_query.for_each_mut(|(mut text, label)| {
+        text.sections[0].value = setting_text(label.option, &settings);
+    });
+}
+
+// waits for a slider to be let go so a drag is written once
+pub fn save_changed_settings(
+    settings: Res<Settings>,
+    slider_query: Query<&Interaction, With<VolumeSlider>>,
+    mut unsaved: Local<bool>
+) {
+    if settings.is_changed() && !settings.is_added() {
+        *unsaved = true;
+    }
+
+    let dragging = slider_query.iter()
+        .any(|interaction| *interaction == Interaction::Pressed);
+
+    if *unsaved && !dragging {
+        settings.save();
+        *unsaved = false;
+    }
+}
+
+pub fn close_settings_menu(mut commands: Commands) {
+    commands.remove_resource::<SettingsMenuOpen>();
+}
@@ -0,0 +1,331 @@
+use bevy::{prelude::*, ui::{FocusPolicy, RelativeCursorPosition}};
+
+use crate::{
+    settings_menu::components::*,
+    settings::resources::*,
+    gamepad::components::Modal,
+    main_menu::styles::*,
+};
+use crate::loading::resources::GameAssets;
+
+const PANEL_COLOR: Color = Color::rgba(0.1, 0.1, 0.1, 0.95);
+const SLIDER_TRACK_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
+const SLIDER_FILL_COLOR: Color = Color::rgb(0.35, 0.75, 0.35);
+const LABEL_WIDTH: f32 = 220.0;
+const SLIDER_WIDTH: f32 = 300.0;
+
+const fn setting_button_style() -> Style {
+    let mut style = Style::DEFAULT;
+    style.width = Val::Px(340.0);
+    style.height = Val::Px(50.0);
+    style.justify_content = JustifyContent::Center;
+    style.align_items = AlignItems::Center;
+
+    style
+}
+
+const fn step_button_style() -> Style {
+    let mut style = Style::DEFAULT;
+    style.width = Val::Px(50.0);
+    style.height = Val::Px(50.0);
+    style.justify_content = JustifyContent::Center;
+    style.align_items = AlignItems::Center;
+
+    style
+}
+
+const SETTING_BUTTON_STYLE: Style = setting_button_style();
+const STEP_BUTTON_STYLE: Style = step_button_style();
+
+pub fn spawn_settings_menu(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    settings: Res<Settings>
+) {
+    build_settings_menu(&mut commands, &game_assets, &settings);
+}
+
+pub fn despawn_settings_menu(
+    mut commands: Commands,
+    settings_menu_query: Query<Entity, With<SettingsMenu>>
+) {
+    if let Ok(settings_menu_entity) = settings_menu_query.get_single() {
+        commands.entity(settings_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn volume_text(volume: f32) -> String {
+    format!("{}%", (volume * 100.0).round())
+}
+
+pub fn mute_text(muted: bool) -> &'static str {
+    if muted { "Unmute" } else { "Mute" }
+}
+
+pub fn setting_text(option: SettingOption, settings: &Settings) -> String {
+    let on_off = |value: bool| if value { "On" } else { "Off" };
+
+    match option {
+        SettingOption::Fullscreen =>
+            format!("Fullscreen: {}", on_off(settings.fullscreen)),
+        SettingOption::Resolution =>
+            format!("Resolution: {}x{}", settings.resolution.x, settings.resolution.y),
+        SettingOption::Vsync =>
+            format!("VSync: {}", on_off(settings.vsync)),
+        SettingOption::PauseOnFocusLoss =>
+            format!("Pause When Unfocused: {}", on_off(settings.pause_on_focus_loss)),
+    }
+}
+
+pub fn build_settings_menu(
+    commands: &mut Commands,
+    game_assets: &Res<GameAssets>,
+    settings: &Settings
+) -> Entity {
+    let settings_menu_entity = commands.spawn(
+        (NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                ..MAIN_MENU_STYLE
+            },
+            background_color: PANEL_COLOR.into(),
+            // drawn over and keeps the mouse away from the menu underneath
+            z_index: ZIndex::Global(10),
+            focus_policy: FocusPolicy::Block,
+            ..default()
+        },
+        SettingsMenu {},
+        Modal {}
+    ))
+    .with_children(|parent| {
+        // === Title ===
+        parent.spawn(
+            NodeBundle {
+                style: TITLE_STYLE,
+                ..default()
+            }
+        ).with_children(|parent| {
+            parent.spawn(
+                TextBundle {
+                    text: Text {
+                        sections: vec![
+                            TextSection::new(
+                                "Settings",
+                                get_title_text_style(game_assets)
+                            )
+                        ],
+                        alignment: TextAlignment::Center,
+                        ..default()
+                    },
+                    ..default()
+                }
+            );
+        });
+        // === Volume ===
+        VolumeBus::ALL.iter().for_each(|bus| {
+            spawn_volume_row(parent, game_assets, settings, *bus);
+        });
+        // === Video ===
+        parent.spawn(
+            NodeBundle {
+                style: OPTIONS_ROW_STYLE,
+                ..default()
+            }
+        ).with_children(|parent| {
+            spawn_setting_button(parent, game_assets, settings, SettingOption::Fullscreen);
+            spawn_setting_button(parent, game_assets, settings, SettingOption::Resolution);
+        });
+        // === Other ===
+        parent.spawn(
+            NodeBundle {
+                style: OPTIONS_ROW_STYLE,
+                ..default()
+            }
+        ).with_children(|parent| {
+            spawn_setting_button(parent, game_assets, settings, SettingOption::Vsync);
+            spawn_setting_button(parent, game_assets, settings, SettingOption::PauseOnFocusLoss);
+        });
+        // === Back Button ===
+        parent.spawn((
+            ButtonBundle {
+                style: SMALL_BUTTON_STYLE,
+                background_color: NORMAL_BUTTON_COLOR.into(),
+                ..default()
+            },
+            BackButton {}
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+            TextBundle {
+                text: Text {
+                    sections: vec![
+                        TextSection::new(
+                            "Back",
+                            get_small_button_text_style(game_assets)
+                    )],
+                    alignment: TextAlignment::Center,
+                    ..default()
+                },
+                ..default()
+            });
+        });
+    })
+    .id();
+
+    settings_menu_entity
+}
+
+fn spawn_volume_row(
+    parent: &mut ChildBuilder,
+    game_assets: &Res<GameAssets>,
+    settings: &Settings,
+    bus: VolumeBus
+) {
+    let volume = settings.volume(bus);
+
+    parent.spawn(
+        NodeBundle {
+            style: OPTIONS_ROW_STYLE,
+            ..default()
+        }
+    ).with_children(|parent| {
+        // Label
+        parent.spawn(
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(LABEL_WIDTH),
+                    ..default()
+                },
+                ..default()
+            }
+        ).with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(
+                    bus.label(),
+                    get_small_button_text_style(game_assets)
+                )
+            );
+        });
+        // -
+        spawn_step_button(parent, game_assets, bus, -0.1);
+        // Track
+        parent.spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(SLIDER_WIDTH),
+                    height: Val::Px(24.0),
+                    ..default()
+                },
+                background_color: SLIDER_TRACK_COLOR.into(),
+                ..default()
+            },
+            RelativeCursorPosition::default(),
+            VolumeSlider { bus }
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(volume * 100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    background_color: SLIDER_FILL_COLOR.into(),
+                    focus_policy: FocusPolicy::Pass,
+                    ..default()
+                },
+                VolumeFill { bus }
+            ));
+        });
+        // +
+        spawn_step_button(parent, game_assets, bus, 0.1);
+        // Value
+        parent.spawn(
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(80.0),
+                    ..default()
+                },
+                ..default()
+            }
+        ).with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    volume_text(volume),
+                    get_small_button_text_style(game_assets)
+                ),
+                VolumeText { bus }
+            ));
+        });
+        // Mute
+        parent.spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(120.0),
+                    ..STEP_BUTTON_STYLE
+                },
+                background_color: NORMAL_BUTTON_COLOR.into(),
+                ..default()
+            },
+            MuteButton { bus }
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    mute_text(settings.is_muted(bus)),
+                    get_small_button_text_style(game_assets)
+                ),
+                MuteLabel { bus }
+            ));
+        });
+    });
+}
+
+fn spawn_step_button(
+    parent: &mut ChildBuilder,
+    game_assets: &Res<GameAssets>,
+    bus: VolumeBus,
+    step: f32
+) {
+    parent.spawn((
+        ButtonBundle {
+            style: STEP_BUTTON_STYLE,
+            background_color: NORMAL_BUTTON_COLOR.into(),
+            ..default()
+        },
+        VolumeStepButton { bus, step }
+    ))
+    .with_children(|parent| {
+        parent.spawn(
+            TextBundle::from_section(
+                if step < 0.0 { "-" } else { "+" },
+                get_small_button_text_style(game_assets)
+            )
+        );
+    });
+}
+
+fn spawn_setting_button(
+    parent: &mut ChildBuilder,
+    game_assets: &Res<GameAssets>,
+    settings: &Settings,
+    option: SettingOption
+) {
+    parent.spawn((
+        ButtonBundle {
+            style: SETTING_BUTTON_STYLE,
+            background_color: NORMAL_BUTTON_COLOR.into(),
+            ..default()
+        },
+        SettingButton { option }
+    ))
+    .with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section(
+                setting_text(option, settings),
+                get_small_button_text_style(game_assets)
+            ),
+            SettingLabel { option }
+        ));
+    });
+}
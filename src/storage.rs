@@ -0,0 +1,91 @@
+
+use std::{
+    fmt,
+    fs,
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+const APP_DIR_NAME: &str = "ball_game";
+// overrides the data directory, e.g. for tests or portable installs
+pub const DATA_DIR_ENV_VAR: &str = "BALL_GAME_DATA_DIR";
+
+#[derive(Debug)]
+pub enum StorageError {
+    NotFound,
+    Io(io::Error),
+    Parse(String),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::NotFound => write!(f, "file not found"),
+            StorageError::Io(error) => write!(f, "io error: {}", error),
+            StorageError::Parse(error) => write!(f, "parse error: {}", error),
+            StorageError::UnsupportedVersion(version) =>
+                write!(f, "unsupported file version {}", version),
+        }
+    }
+}
+
+impl From<io::Error> for StorageError {
+    fn from(error: io::Error) -> Self {
+        if error.kind() == io::ErrorKind::NotFound {
+            StorageError::NotFound
+        } else {
+            StorageError::Io(error)
+        }
+    }
+}
+
+pub fn data_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(DATA_DIR_ENV_VAR) {
+        return Some(PathBuf::from(dir));
+    }
+
+    dirs::data_dir().map(|dir| dir.join(APP_DIR_NAME))
+}
+
+pub fn data_file(file_name: &str) -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join(file_name))
+}
+
+pub fn load_ron<T: DeserializeOwned>(path: &Path) -> Result<T, StorageError> {
+    let contents = fs::read_to_string(path)?;
+
+    ron::from_str(&contents)
+        .map_err(|error| StorageError::Parse(error.to_string()))
+}
+
+pub fn save_ron<T: Serialize>(path: &Path, value: &T) -> Result<(), StorageError> {
+    let contents = ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::new())
+        .map_err(|error| StorageError::Parse(error.to_string()))?;
+
+    save_bytes(path, contents.as_bytes())
+}
+
+// writes to a temporary file first so a crash mid-write
+// never leaves a half written file behind
+pub fn save_bytes(path: &Path, contents: &[u8]) -> Result<(), StorageError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let temp_path = path.with_extension("tmp");
+
+    fs::write(&temp_path, contents)?;
+    fs::rename(&temp_path, path)?;
+
+    Ok(())
+}
+
+// keeps an unreadable file around for inspection instead of overwriting it
+pub fn back_up_file(path: &Path) -> Option<PathBuf> {
+    let backup_path = path.with_extension("bak");
+
+    fs::rename(path, &backup_path).ok().map(|_| backup_path)
+}
@@ -1,70 +1,106 @@
 
-use bevy::{prelude::*, window::{PrimaryWindow, WindowResized}, app::AppExit};
+use bevy::{prelude::*, render::camera::ScalingMode, app::AppExit};
 
-use crate::{events::*, AppState};
+use crate::{events::*, controls::resources::InputAction, AppState};
+use crate::game::resources::Arena;
+
+// long enough to cover the window past the arena at any aspect ratio
+const LETTERBOX_BAR_LENGTH: f32 = 100_000.0;
+// in front of everything in the arena
+const LETTERBOX_Z: f32 = 100.0;
 
 #[derive(Component)]
 pub struct Camera;
 
-pub fn spawn_camera(
-    mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>
-) {
-    let window = window_query.get_single().unwrap();
+// one of the black bars hiding everything outside the arena,
+// edge points from the arena to the side it covers
+#[derive(Component)]
+pub struct Letterbox {
+    pub edge: Vec2,
+}
 
+pub fn spawn_camera(mut commands: Commands) {
     commands.spawn((
-        Camera2dBundle {
-            transform: Transform::from_xyz(window.width() / 2.0, 
-                                        window.height() / 2.0, 0.0),
-            ..default()
-        },
+        Camera2dBundle::default(),
         Camera {}
-    )
-    );
+    ));
+
+    [Vec2::NEG_X, Vec2::X, Vec2::NEG_Y, Vec2::Y].into_iter().for_each(|edge| {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::BLACK,
+                    ..default()
+                },
+                ..default()
+            },
+            Letterbox { edge }
+        ));
+    });
 }
 
-pub fn move_camera_when_window_resize(
-    mut resize_event: EventReader<WindowResized>,
-    mut camera_query: Query<&mut Transform, With<Camera>>
+// the whole arena stays in view at the largest scale the window allows
+pub fn fit_camera_to_arena(
+    arena: Res<Arena>,
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<Camera>>,
+    mut letterbox_query: Query<(&mut Transform, &mut Sprite, &Letterbox), Without<Camera>>
 ) {
-    resize_event.read().for_each(|event| {
-        let mut camera_transform = camera_query.single_mut();
-        camera_transform.translation.x = event.width / 2.0;
-        camera_transform.translation.y = event.height / 2.0;
+    if let Ok((mut transform, mut projection)) = camera_query.get_single_mut() {
+        let center = arena.center();
+
+        transform.translation.x = center.x;
+        transform.translation.y = center.y;
+        projection.scaling_mode = ScalingMode::AutoMin {
+            min_width: arena.width(),
+            min_height: arena.height(),
+        };
+    }
+
+    letterbox_query.for_each_mut(|(mut transform, mut sprite, letterbox)| {
+        // the side bars run the full height so the corners are covered too
+        let (offset, size) = if letterbox.edge.x != 0.0 {
+            (Vec2::new(arena.width() + LETTERBOX_BAR_LENGTH, 0.0) / 2.0,
+                Vec2::new(LETTERBOX_BAR_LENGTH, arena.height() + LETTERBOX_BAR_LENGTH * 2.0))
+        } else {
+            (Vec2::new(0.0, arena.height() + LETTERBOX_BAR_LENGTH) / 2.0,
+                Vec2::new(arena.width(), LETTERBOX_BAR_LENGTH))
+        };
+
+        transform.translation = (arena.center() + offset * letterbox.edge)
+            .extend(LETTERBOX_Z);
+        sprite.custom_size = Some(size);
     });
 }
 
 pub fn transition_to_game_state(
-    keyboard_input: Res<Input<KeyCode>>,
+    action_input: Res<Input<InputAction>>,
     app_state: Res<State<AppState>>,
     mut next_app_state: ResMut<NextState<AppState>>
 ) {
-    if keyboard_input.just_pressed(KeyCode::G) {
-        if *app_state.get() != AppState::Game {
+    if action_input.just_pressed(InputAction::StartGame) && 
+        *app_state.get() != AppState::Game {
             next_app_state.set(AppState::Game);
             println!("Transitioning to Game State");
-        }
     }
 }
 
 pub fn transition_to_main_menu_state(
-    keyboard_input: Res<Input<KeyCode>>,
+    action_input: Res<Input<InputAction>>,
     app_state: Res<State<AppState>>,
     mut next_app_state: ResMut<NextState<AppState>>
 ) {
-    if keyboard_input.just_pressed(KeyCode::M) {
-        if *app_state.get() != AppState::MainMenu {
+    if action_input.just_pressed(InputAction::QuitToMenu) && 
+        *app_state.get() != AppState::MainMenu {
             next_app_state.set(AppState::MainMenu);
             println!("Transitioning to Main Menu State");
-        }
     }
 }
 
 pub fn exit_game(
-    keyboard_input: Res<Input<KeyCode>>,
+    action_input: Res<Input<InputAction>>,
     mut app_exit_event_writer: EventWriter<AppExit>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Escape) {
+    if action_input.just_pressed(InputAction::Quit) {
         app_exit_event_writer.send(AppExit);
     }
 }
@@ -0,0 +1,57 @@
+mod common;
+
+use bevy::{prelude::*, render::camera::ScalingMode, ecs::system::RunSystemOnce};
+
+use ball_game::systems::{spawn_camera, fit_camera_to_arena, Camera, Letterbox};
+use ball_game::game::config::GameConfig;
+use ball_game::game::resources::Arena;
+
+use common::*;
+
+fn camera_app() -> App {
+    let mut app = test_app();
+
+    // startup has already run by now
+    app.world.run_system_once(spawn_camera);
+    app.add_systems(Update, fit_camera_to_arena.run_if(resource_changed::<Arena>()));
+    update(&mut app, 1);
+    app
+}
+
+#[test]
+fn resizing_the_window_leaves_the_arena_alone() {
+    let mut app = camera_app();
+    let configured = app.world.resource::<GameConfig>().arena.clone();
+    let window = app.world.resource::<TestWindow>().0;
+
+    press_key(&mut app, KeyCode::G);
+    app.world.get_mut::<Window>(window).unwrap().resolution.set(640.0, 900.0);
+    update(&mut app, 2);
+
+    let arena = app.world.resource::<Arena>();
+    assert_eq!(arena.size, Vec2::new(configured.width, configured.height));
+}
+
+#[test]
+fn the_camera_frames_the_whole_arena() {
+    let mut app = camera_app();
+    let arena = *app.world.resource::<Arena>();
+
+    let mut camera_query = app.world
+        .query_filtered::<(&Transform, &OrthographicProjection), With<Camera>>();
+    let (transform, projection) = camera_query.single(&app.world);
+    assert_eq!(transform.translation.truncate(), arena.center());
+    assert!(matches!(projection.scaling_mode,
+        ScalingMode::AutoMin { min_width, min_height }
+            if min_width == arena.width() && min_height == arena.height()));
+
+    // the bars start right at the arena's edges
+    let mut letterbox_query = app.world.query::<(&Transform, &Sprite, &Letterbox)>();
+    assert_eq!(letterbox_query.iter(&app.world).count(), 4);
+    letterbox_query.iter(&app.world).for_each(|(transform, sprite, letterbox)| {
+        let half_size = sprite.custom_size.unwrap() / 2.0;
+        let inner_edge = transform.translation.truncate() - letterbox.edge * half_size;
+        let arena_edge = arena.center() + letterbox.edge * arena.size / 2.0;
+        assert_eq!(inner_edge.dot(letterbox.edge), arena_edge.dot(letterbox.edge));
+    });
+}
@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use ball_game::game::config::GameConfig;
+use ball_game::loading::resources::GameAssets;
+use ball_game::music::config::{MusicConfig, MUSIC_CONFIG_PATH};
+
+// the loading screen stops on a missing file, this catches it before a release
+#[test]
+fn every_asset_in_the_manifest_exists() {
+    let assets_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets");
+    let config = GameConfig::load_from(&assets_dir.join("config/game.ron")).unwrap();
+    let music_config = MusicConfig::load_from(&assets_dir.join(MUSIC_CONFIG_PATH)).unwrap();
+    let missing: Vec<_> = GameAssets::default().manifest().into_iter()
+        .map(|(path, _)| path)
+        // enemy sprites and music come from the configs rather than the code
+        .chain(config.enemy.archetypes.into_iter().map(|archetype| archetype.sprite))
+        .chain(music_config.every_track().cloned())
+        .filter(|path| !assets_dir.join(path).is_file())
+        .collect();
+
+    assert!(missing.is_empty(), "missing assets: {:?}", missing);
+}
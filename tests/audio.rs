@@ -0,0 +1,50 @@
+mod common;
+
+use bevy::prelude::*;
+
+use ball_game::events::{PlaySound, SoundEffect};
+use ball_game::game::sound::components::PlayingSound;
+use ball_game::settings::resources::Settings;
+
+use common::*;
+
+fn play(app: &mut App, sound: SoundEffect, times: usize) {
+    (0..times).for_each(|_| app.world.send_event(PlaySound { sound }));
+    app.update();
+}
+
+fn playing(app: &mut App, sound: SoundEffect) -> usize {
+    app.world.query::<&PlayingSound>()
+        .iter(&app.world)
+        .filter(|playing_sound| playing_sound.sound == sound)
+        .count()
+}
+
+// there is no audio output in the tests, so nothing ever finishes playing
+#[test]
+fn sounds_over_their_limit_are_dropped() {
+    let mut app = test_app();
+
+    play(&mut app, SoundEffect::EnemyBounce, 4);
+    assert_eq!(playing(&mut app, SoundEffect::EnemyBounce), 2);
+
+    play(&mut app, SoundEffect::EnemyBounce, 1);
+    assert_eq!(playing(&mut app, SoundEffect::EnemyBounce), 2);
+
+    // each sound has its own limit
+    play(&mut app, SoundEffect::StarCollected, 1);
+    assert_eq!(playing(&mut app, SoundEffect::StarCollected), 1);
+}
+
+#[test]
+fn a_muted_bus_plays_nothing() {
+    let mut app = test_app();
+
+    app.world.resource_mut::<Settings>().sfx_muted = true;
+    play(&mut app, SoundEffect::PlayerHit, 1);
+    assert_eq!(playing(&mut app, SoundEffect::PlayerHit), 0);
+
+    app.world.resource_mut::<Settings>().sfx_muted = false;
+    play(&mut app, SoundEffect::PlayerHit, 1);
+    assert_eq!(playing(&mut app, SoundEffect::PlayerHit), 1);
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use bevy::{prelude::*, ecs::event::ManualEventReader};
+
+use ball_game::events::{CollisionStarted, CollisionEnded};
+use ball_game::game::player::components::{Invulnerable, Player};
+use ball_game::game::player::resources::Lives;
+use ball_game::game::physics::components::{Position, Collider, CollisionLayer};
+
+use common::*;
+
+// a layer nothing in the game listens to, so only the test's pair collides
+const TEST_LAYER: CollisionLayer = CollisionLayer(1 << 16);
+
+fn test_collider(radius: f32) -> Collider {
+    Collider::circle(radius, TEST_LAYER, TEST_LAYER)
+}
+
+// how many of the events sent since the reader last looked are about the pair
+fn read_pair<E: Event>(
+    app: &App,
+    reader: &mut ManualEventReader<E>,
+    entities: [Entity; 2],
+    pair: impl Fn(&E) -> [Entity; 2]
+) -> usize {
+    reader.read(app.world.resource::<Events<E>>())
+        .filter(|event| {
+            let mut event_entities = pair(event);
+            event_entities.sort();
+            event_entities == entities
+        })
+        .count()
+}
+
+#[test]
+fn a_contact_starts_once_and_ends_when_the_pair_separates() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+
+    let mut started = ManualEventReader::<CollisionStarted>::default();
+    let mut ended = ManualEventReader::<CollisionEnded>::default();
+    let a = app.world.spawn((Position::new(Vec2::new(40.0, 40.0)), test_collider(10.0))).id();
+    let b = app.world.spawn((Position::new(Vec2::new(55.0, 40.0)), test_collider(10.0))).id();
+    let mut entities = [a, b];
+    entities.sort();
+
+    // events only live for two updates, read them right away
+    update(&mut app, 1);
+    assert_eq!(read_pair(&app, &mut started, entities, |event| event.entities), 1);
+    assert_eq!(read_pair(&app, &mut ended, entities, |event| event.entities), 0);
+
+    app.world.get_mut::<Position>(b).unwrap().teleport(Vec2::new(200.0, 40.0));
+    update(&mut app, 1);
+    assert_eq!(read_pair(&app, &mut started, entities, |event| event.entities), 0);
+    assert_eq!(read_pair(&app, &mut ended, entities, |event| event.entities), 1);
+}
+
+#[test]
+fn colliders_on_unrelated_layers_do_not_collide() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+
+    let mut started = ManualEventReader::<CollisionStarted>::default();
+    let a = app.world.spawn((Position::new(Vec2::new(40.0, 40.0)), test_collider(10.0))).id();
+    let b = app.world.spawn((
+        Position::new(Vec2::new(40.0, 40.0)),
+        Collider::circle(10.0, CollisionLayer(1 << 17), CollisionLayer(1 << 17)),
+    )).id();
+    let mut entities = [a, b];
+    entities.sort();
+
+    update(&mut app, 1);
+    assert_eq!(read_pair(&app, &mut started, entities, |event| event.entities), 0);
+}
+
+#[test]
+fn an_enemy_still_on_the_player_hits_once_the_blinking_ends() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+
+    let lives = app.world.resource::<Lives>().remaining;
+    let player = app.world.query_filtered::<Entity, With<Player>>().single(&app.world);
+    app.world.entity_mut(player).insert(Invulnerable::new(0.5));
+
+    // held on the player from the first blinking tick until a life goes
+    let mut ticks = 0;
+    while app.world.resource::<Lives>().remaining == lives && ticks < 60 {
+        collide_enemy_with_player(&mut app);
+        update(&mut app, 1);
+        ticks += 1;
+    }
+
+    assert_eq!(app.world.resource::<Lives>().remaining, lives - 1,
+            "the enemy resting on the player never hit it");
+    assert!(ticks >= 30, "hit after {} ticks, while still blinking", ticks);
+}
+
+#[test]
+fn boxes_collide_by_their_corners() {
+    let square = Collider::aabb(Vec2::splat(10.0), TEST_LAYER, TEST_LAYER);
+    let ball = test_collider(5.0);
+
+    // inside the box's bounding circle but off its corner
+    assert!(!ball.overlaps(Vec2::new(14.0, 14.0), &square, Vec2::ZERO));
+    assert!(ball.overlaps(Vec2::new(12.0, 12.0), &square, Vec2::ZERO));
+    assert!(square.overlaps(Vec2::ZERO, &ball, Vec2::new(14.0, 0.0)));
+    assert!(square.overlaps(Vec2::ZERO, &square, Vec2::new(19.0, -19.0)));
+    assert!(!square.overlaps(Vec2::ZERO, &square, Vec2::new(21.0, 0.0)));
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use bevy::{prelude::*, ecs::event::ManualEventReader};
+
+use ball_game::events::GameOver;
+use ball_game::game::config::{GameConfig, GameConfigError};
+use ball_game::game::player::resources::Lives;
+use ball_game::game::star::components::Star;
+use ball_game::game::score::resources::{Score, Combo};
+use ball_game::game::physics::components::{Position, Collider, CollisionLayer};
+
+use common::*;
+
+// drops a star on the player, picked up on the next fixed tick
+fn collect_star(app: &mut App) {
+    let position = player_position(app);
+    app.world.spawn((
+        Position::new(position),
+        Star {},
+        Collider::aabb(Vec2::splat(15.0), CollisionLayer::STAR, CollisionLayer::NONE),
+    ));
+    update(app, 1);
+}
+
+fn score(app: &App) -> u32 {
+    app.world.resource::<Score>().value
+}
+
+fn combo(app: &App) -> &Combo {
+    app.world.resource::<Combo>()
+}
+
+#[test]
+fn quick_pickups_raise_the_multiplier() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+    let config = app.world.resource::<GameConfig>().combo.clone();
+    let score_before = score(&app);
+
+    (0..config.stars_per_step).for_each(|_| collect_star(&mut app));
+    assert_eq!(combo(&app).count, config.stars_per_step);
+    assert_eq!(combo(&app).multiplier(&config), 2.min(config.max_multiplier));
+
+    // the star that completes a step is already worth the new multiplier
+    let expected = config.stars_per_step - 1 + combo(&app).multiplier(&config);
+    assert_eq!(score(&app) - score_before, expected);
+}
+
+#[test]
+fn the_combo_runs_out_without_more_stars() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+    let window = app.world.resource::<GameConfig>().combo.window;
+
+    collect_star(&mut app);
+    collect_star(&mut app);
+    assert_eq!(combo(&app).count, 2);
+    assert!(combo(&app).time_left_fraction() > 0.9);
+
+    // nothing spawned in the meantime gets picked up by chance
+    (0..(window * 60.0) as usize + 2).for_each(|_| {
+        let stars: Vec<Entity> = app.world.query_filtered::<Entity, With<Star>>()
+            .iter(&app.world)
+            .collect();
+        stars.into_iter().for_each(|star| {
+            app.world.despawn(star);
+        });
+        update(&mut app, 1);
+    });
+    assert_eq!(combo(&app).count, 0);
+    assert_eq!(combo(&app).time_left_fraction(), 0.0);
+    assert_eq!(combo(&app).best, 2);
+}
+
+#[test]
+fn game_over_reports_the_best_combo() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+    app.world.resource_mut::<Lives>().remaining = 1;
+
+    (0..3).for_each(|_| collect_star(&mut app));
+    let final_score = score(&app);
+
+    let mut game_over = ManualEventReader::<GameOver>::default();
+    collide_enemy_with_player(&mut app);
+    update(&mut app, 1);
+
+    let events: Vec<_> = game_over.read(app.world.resource::<Events<GameOver>>())
+        .map(|event| (event.score, event.best_combo))
+        .collect();
+    assert_eq!(events, vec![(final_score, 3)]);
+}
+
+#[test]
+fn a_combo_step_must_take_at_least_one_star() {
+    let mut config = GameConfig::default();
+    config.combo.stars_per_step = 0;
+
+    match config.validate() {
+        Err(GameConfigError::Invalid(problems)) => assert!(
+            problems.iter().any(|problem| problem.contains("combo.stars_per_step")),
+            "{:?}", problems),
+        result => panic!("expected the combo step to be reported, got {:?}", result),
+    }
+}
@@ -0,0 +1,185 @@
+// every test binary uses a different part of the harness
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use bevy::{
+    prelude::*,
+    input::{
+        keyboard::KeyboardInput,
+        gamepad::{
+            GamepadAxisChangedEvent,
+            GamepadButtonChangedEvent,
+            GamepadConnection,
+            GamepadConnectionEvent,
+            GamepadInfo,
+        },
+        ButtonState,
+        InputPlugin,
+    },
+    time::TimeUpdateStrategy,
+    window::{PrimaryWindow, ReceivedCharacter, WindowFocused},
+};
+
+use ball_game::AppState;
+use ball_game::systems::*;
+use ball_game::storage::DATA_DIR_ENV_VAR;
+use ball_game::game::{GamePlugin, SimulationState, FIXED_TIMESTEP_HZ};
+use ball_game::game::player::components::Player;
+use ball_game::game::enemy::components::Enemy;
+use ball_game::game::physics::components::Position;
+use ball_game::game::rng::resources::FixedSeed;
+use ball_game::game::score::resources::PendingHighScore;
+use ball_game::main_menu::MainMenuPlugin;
+use ball_game::controls::{ControlsPlugin, resources::PendingRebind};
+use ball_game::controls_menu::ControlsMenuPlugin;
+use ball_game::settings::SettingsPlugin;
+use ball_game::settings_menu::SettingsMenuPlugin;
+use ball_game::music::MusicPlugin;
+use ball_game::loading::resources::GameAssets;
+
+pub const TEST_SEED: u64 = 1234;
+
+// the window input events are sent to, there is no real one
+#[derive(Resource)]
+pub struct TestWindow(pub Entity);
+
+// the game plugins on top of minimal plugins, with a stand in window and
+// one fixed tick per update so every run of a test plays out the same
+pub fn test_app() -> App {
+    // keeps high scores and replays out of the real data directory
+    let data_dir = std::env::temp_dir()
+        .join(format!("ball_game_tests_{}", std::process::id()));
+    std::env::set_var(DATA_DIR_ENV_VAR, data_dir);
+
+    let mut app = App::new();
+
+    app
+    .add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        InputPlugin,
+        ControlsPlugin,
+        SettingsPlugin,
+        GamePlugin::default(),
+        MainMenuPlugin,
+        ControlsMenuPlugin,
+        SettingsMenuPlugin,
+        MusicPlugin,
+    ))
+    .init_asset::<Image>()
+    .init_asset::<Font>()
+    .init_asset::<AudioSource>()
+    // sent by the window plugin, read by the high score name entry
+    .add_event::<ReceivedCharacter>()
+    .add_event::<WindowFocused>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(
+        Duration::from_secs_f64(1.0 / FIXED_TIMESTEP_HZ)))
+    .insert_resource(FixedSeed { seed: Some(TEST_SEED) })
+    .add_state::<AppState>()
+    // there are no asset loaders here, the empty handles draw nothing
+    .insert_resource(GameAssets::default())
+    .insert_resource(NextState(Some(AppState::MainMenu)))
+    .add_systems(Update, (
+        handle_game_over,
+        (
+            transition_to_game_state,
+            transition_to_main_menu_state
+        )
+        .run_if(not(resource_exists::<PendingHighScore>()))
+        .run_if(not(resource_exists::<PendingRebind>()))
+    ));
+
+    let window = app.world.spawn((Window::default(), PrimaryWindow)).id();
+    app.insert_resource(TestWindow(window));
+
+    app.update();
+    app
+}
+
+pub fn update(app: &mut App, frames: usize) {
+    (0..frames).for_each(|_| app.update());
+}
+
+// presses and releases a key, leaving time for any state change to apply
+pub fn press_key(app: &mut App, key_code: KeyCode) {
+    send_key(app, key_code, ButtonState::Pressed);
+    app.update();
+    send_key(app, key_code, ButtonState::Released);
+    update(app, 2);
+}
+
+fn send_key(app: &mut App, key_code: KeyCode, state: ButtonState) {
+    let window = app.world.resource::<TestWindow>().0;
+
+    app.world.send_event(KeyboardInput {
+        scan_code: 0,
+        key_code: Some(key_code),
+        state,
+        window,
+    });
+}
+
+pub fn connect_gamepad(app: &mut App) -> Gamepad {
+    let gamepad = Gamepad::new(0);
+
+    app.world.send_event(GamepadConnectionEvent {
+        gamepad,
+        connection: GamepadConnection::Connected(GamepadInfo {
+            name: "Test Gamepad".to_string(),
+        }),
+    });
+    app.update();
+
+    gamepad
+}
+
+pub fn press_gamepad_button(
+    app: &mut App,
+    gamepad: Gamepad,
+    button_type: GamepadButtonType
+) {
+    app.world.send_event(GamepadButtonChangedEvent { gamepad, button_type, value: 1.0 });
+    app.update();
+    app.world.send_event(GamepadButtonChangedEvent { gamepad, button_type, value: 0.0 });
+    update(app, 2);
+}
+
+pub fn set_gamepad_axis(
+    app: &mut App,
+    gamepad: Gamepad,
+    axis_type: GamepadAxisType,
+    value: f32
+) {
+    app.world.send_event(GamepadAxisChangedEvent { gamepad, axis_type, value });
+}
+
+pub fn app_state(app: &App) -> AppState {
+    *app.world.resource::<State<AppState>>().get()
+}
+
+pub fn count<T: Component>(app: &mut App) -> usize {
+    app.world.query_filtered::<(), With<T>>().iter(&app.world).count()
+}
+
+pub fn simulation_state(app: &App) -> SimulationState {
+    *app.world.resource::<State<SimulationState>>().get()
+}
+
+pub fn player_position(app: &mut App) -> Vec2 {
+    app.world.query_filtered::<&Position, With<Player>>()
+        .single(&app.world)
+        .current
+}
+
+// moves the first enemy onto the player so the next fixed tick lands a hit
+pub fn collide_enemy_with_player(app: &mut App) -> Entity {
+    let player_position = player_position(app);
+    let mut enemy_query = app.world.query_filtered::<(Entity, &mut Position), With<Enemy>>();
+    let (enemy_entity, mut enemy_position) = enemy_query.iter_mut(&mut app.world)
+        .next()
+        .expect("no enemy to collide with");
+
+    enemy_position.teleport(player_position);
+    enemy_entity
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use bevy::prelude::*;
+
+use ball_game::AppState;
+use ball_game::game::SimulationState;
+use ball_game::controls::resources::*;
+use ball_game::controls_menu::components::{BindingButton, StatusText};
+
+use common::*;
+
+fn open_controls_menu(app: &mut App) {
+    app.world.resource_mut::<NextState<AppState>>().set(AppState::Controls);
+    update(app, 2);
+    assert_eq!(app_state(app), AppState::Controls);
+}
+
+// there is no ui plugin in the tests, so the click is set by hand
+fn click_binding_button(app: &mut App, action: InputAction, slot: BindingSlot) {
+    let mut button_query = app.world.query::<(&mut Interaction, &BindingButton)>();
+    let (mut interaction, _) = button_query.iter_mut(&mut app.world)
+        .find(|(_, button)| button.action == action && button.slot == slot)
+        .expect("no button for the binding");
+
+    *interaction = Interaction::Pressed;
+    update(app, 2);
+    assert!(app.world.contains_resource::<PendingRebind>());
+}
+
+fn status_text(app: &mut App) -> String {
+    app.world.query_filtered::<&Text, With<StatusText>>()
+        .single(&app.world)
+        .sections[0].value.clone()
+}
+
+#[test]
+fn a_rebound_pause_key_pauses_the_game() {
+    let mut app = test_app();
+
+    open_controls_menu(&mut app);
+    click_binding_button(&mut app, InputAction::Pause, BindingSlot::Key(0));
+    press_key(&mut app, KeyCode::P);
+
+    assert!(!app.world.contains_resource::<PendingRebind>());
+    assert_eq!(app.world.resource::<InputBindings>()
+                .get(InputAction::Pause, BindingSlot::Key(0)),
+            Some(Binding::Key(KeyCode::P)));
+    // the binding survives a restart
+    assert_eq!(InputBindings::load()
+                .get(InputAction::Pause, BindingSlot::Key(0)),
+            Some(Binding::Key(KeyCode::P)));
+
+    press_key(&mut app, KeyCode::M);
+    press_key(&mut app, KeyCode::G);
+    assert_eq!(app_state(&app), AppState::Game);
+
+    press_key(&mut app, KeyCode::Space);
+    assert_eq!(simulation_state(&app), SimulationState::Running);
+    press_key(&mut app, KeyCode::P);
+    assert_eq!(simulation_state(&app), SimulationState::Paused);
+}
+
+#[test]
+fn a_key_bound_to_another_action_is_refused() {
+    let mut app = test_app();
+
+    open_controls_menu(&mut app);
+    click_binding_button(&mut app, InputAction::Confirm, BindingSlot::Key(1));
+    press_key(&mut app, KeyCode::W);
+
+    // still waiting for a key that is free
+    assert!(app.world.contains_resource::<PendingRebind>());
+    assert_eq!(app.world.resource::<InputBindings>()
+                .get(InputAction::Confirm, BindingSlot::Key(1)),
+            None);
+    assert!(status_text(&mut app).contains("already bound to Move Up"));
+
+    press_key(&mut app, KeyCode::Escape);
+    assert!(!app.world.contains_resource::<PendingRebind>());
+    assert_eq!(app_state(&app), AppState::Controls);
+}
@@ -0,0 +1,174 @@
+mod common;
+
+use std::path::Path;
+
+use bevy::prelude::*;
+
+use ball_game::game::config::{GameConfig, GameConfigError, EnemyBehaviour};
+use ball_game::game::enemy::components::Enemy;
+use ball_game::game::physics::components::{Position, Collider, ColliderShape};
+use ball_game::game::difficulty::Difficulty;
+
+use common::*;
+
+// turns the nth enemy into the named archetype, placed and aimed as asked
+fn make_enemy(
+    app: &mut App,
+    index: usize,
+    name: &str,
+    position: Vec2,
+    direction: Vec2
+) -> Entity {
+    let config = app.world.resource::<GameConfig>();
+    let archetype = config.archetype_index(name)
+        .unwrap_or_else(|| panic!("no {} archetype", name));
+    let archetype_config = config.enemy.archetypes[archetype].clone();
+
+    let mut enemy_query = app.world
+        .query::<(Entity, &mut Position, &mut Enemy, &mut Collider)>();
+    let (entity, mut enemy_position, mut enemy, mut collider) = enemy_query
+        .iter_mut(&mut app.world)
+        .nth(index)
+        .expect("no enemy to change");
+
+    enemy_position.current = position;
+    enemy_position.previous = position;
+    enemy.archetype = archetype;
+    enemy.direction = direction;
+    enemy.speed = archetype_config.speed;
+    enemy.size = archetype_config.size;
+    collider.shape = ColliderShape::Circle { radius: archetype_config.radius };
+    enemy.mass = archetype_config.mass;
+    entity
+}
+
+fn enemy(app: &App, entity: Entity) -> &Enemy {
+    app.world.get::<Enemy>(entity).unwrap()
+}
+
+#[test]
+fn the_shipped_archetypes_are_valid() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/config/game.ron");
+    let config = GameConfig::load_from(&path).unwrap();
+
+    assert!(config.enemy.archetypes.len() > 1);
+}
+
+#[test]
+fn a_splitter_must_split_into_a_defined_archetype() {
+    let mut config = GameConfig::default();
+    let mut splitter = config.enemy.archetypes[0].clone();
+    splitter.name = "splitter".to_string();
+    splitter.behaviour = EnemyBehaviour::Splitter { bounces: 1, into: "missing".to_string() };
+    config.enemy.archetypes.push(splitter);
+
+    match config.validate() {
+        Err(GameConfigError::Invalid(problems)) => assert!(
+            problems.iter().any(|problem| problem.contains("missing")),
+            "{:?}", problems),
+        result => panic!("expected the missing archetype to be reported, got {:?}", result),
+    }
+}
+
+#[test]
+fn homing_enemies_turn_toward_the_player() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+
+    let player = player_position(&mut app);
+    let start = player + Vec2::new(300.0, 0.0);
+    let entity = make_enemy(&mut app, 0, "homing", start, Vec2::Y);
+    update(&mut app, 30);
+
+    let toward_player = (player - start).normalize();
+    assert!(enemy(&app, entity).direction.dot(toward_player) > 0.0,
+            "still heading away from the player");
+}
+
+#[test]
+fn accelerating_enemies_speed_up_to_their_limit() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+
+    let position = player_position(&mut app) + Vec2::new(200.0, 0.0);
+    let entity = make_enemy(&mut app, 0, "accelerator", position, Vec2::Y);
+    let speed = enemy(&app, entity).speed;
+    update(&mut app, 10);
+    assert!(enemy(&app, entity).speed > speed);
+
+    let EnemyBehaviour::Accelerating { max_speed, .. } = app.world.resource::<GameConfig>()
+        .enemy.archetypes[enemy(&app, entity).archetype].behaviour else {
+        panic!("not an accelerating archetype");
+    };
+    app.world.get_mut::<Enemy>(entity).unwrap().speed = max_speed - 0.01;
+    update(&mut app, 10);
+    assert_eq!(enemy(&app, entity).speed, max_speed);
+}
+
+#[test]
+fn splitters_break_in_two_on_their_last_bounce() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+
+    let config = app.world.resource::<GameConfig>().clone();
+    let splitter = config.archetype_index("splitter").unwrap();
+    let EnemyBehaviour::Splitter { bounces, into } =
+        &config.enemy.archetypes[splitter].behaviour else {
+        panic!("not a splitter");
+    };
+    let into = config.archetype_index(into).unwrap();
+
+    // one short of splitting, right up against the left wall
+    let position = Vec2::new(0.0, player_position(&mut app).y + 200.0);
+    let entity = make_enemy(&mut app, 0, "splitter", position, Vec2::NEG_X);
+    app.world.get_mut::<Enemy>(entity).unwrap().bounces = bounces - 1;
+    let enemies = count::<Enemy>(&mut app);
+    update(&mut app, 2);
+
+    assert!(app.world.get_entity(entity).is_none(), "the splitter is still there");
+    assert_eq!(count::<Enemy>(&mut app), enemies + 1);
+    let mut enemy_query = app.world.query::<&Enemy>();
+    assert_eq!(enemy_query.iter(&app.world)
+                .filter(|enemy| enemy.archetype == into)
+                .count(), 2);
+}
+
+// two equal enemies overlapping head on, above the player and clear of the walls
+fn collide_head_on(app: &mut App, difficulty: Difficulty) -> (Entity, Entity) {
+    *app.world.resource_mut::<Difficulty>() = difficulty;
+    press_key(app, KeyCode::G);
+
+    let center = player_position(app) + Vec2::new(0.0, 200.0);
+    let left = make_enemy(app, 0, "bouncer", center - Vec2::new(30.0, 0.0), Vec2::X);
+    let right = make_enemy(app, 1, "bouncer", center + Vec2::new(30.0, 0.0), Vec2::NEG_X);
+    update(app, 2);
+    (left, right)
+}
+
+#[test]
+fn enemies_bounce_off_each_other_when_the_difficulty_allows() {
+    let mut app = test_app();
+    assert!(app.world.resource::<GameConfig>().enemy_collisions(Difficulty::Hard));
+
+    let (left, right) = collide_head_on(&mut app, Difficulty::Hard);
+
+    // equal masses trade velocities
+    assert_eq!(enemy(&app, left).direction, Vec2::NEG_X);
+    assert_eq!(enemy(&app, right).direction, Vec2::X);
+    let distance = app.world.get::<Position>(left).unwrap().current
+        .distance(app.world.get::<Position>(right).unwrap().current);
+    let radius = |entity| app.world.get::<Collider>(entity).unwrap().shape.bounding_radius();
+    assert!(distance >= radius(left) + radius(right) - 0.01,
+            "still overlapping, {} apart", distance);
+}
+
+#[test]
+fn enemies_pass_through_each_other_otherwise() {
+    let mut app = test_app();
+    assert!(!app.world.resource::<GameConfig>().enemy_collisions(Difficulty::Normal));
+
+    let (left, right) = collide_head_on(&mut app, Difficulty::Normal);
+
+    assert_eq!(enemy(&app, left).direction, Vec2::X);
+    assert_eq!(enemy(&app, right).direction, Vec2::NEG_X);
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use bevy::prelude::*;
+
+use ball_game::game::SimulationState;
+use ball_game::game::config::GameConfig;
+use ball_game::game::player::STICK_DEADZONE;
+use ball_game::game::FIXED_TIMESTEP_HZ;
+
+use common::*;
+
+#[test]
+fn start_pauses_and_resumes() {
+    let mut app = test_app();
+    let gamepad = connect_gamepad(&mut app);
+
+    press_key(&mut app, KeyCode::G);
+    press_gamepad_button(&mut app, gamepad, GamepadButtonType::Start);
+
+    assert_eq!(*app.world.resource::<State<SimulationState>>().get(),
+            SimulationState::Paused);
+
+    press_gamepad_button(&mut app, gamepad, GamepadButtonType::Start);
+
+    assert_eq!(*app.world.resource::<State<SimulationState>>().get(),
+            SimulationState::Running);
+}
+
+#[test]
+fn stick_speed_is_proportional_to_tilt() {
+    let mut app = test_app();
+    let gamepad = connect_gamepad(&mut app);
+    let ticks = 30;
+
+    press_key(&mut app, KeyCode::G);
+
+    // inside the deadzone nothing moves
+    set_gamepad_axis(&mut app, gamepad, GamepadAxisType::LeftStickX, STICK_DEADZONE / 2.0);
+    let start = player_position(&mut app);
+    update(&mut app, ticks);
+    assert_eq!(player_position(&mut app), start);
+
+    // half way between the deadzone and the edge is half speed
+    let tilt = STICK_DEADZONE + (1.0 - STICK_DEADZONE) / 2.0;
+    set_gamepad_axis(&mut app, gamepad, GamepadAxisType::LeftStickX, tilt);
+    app.update();
+    let start = player_position(&mut app);
+    update(&mut app, ticks);
+
+    let speed = app.world.resource::<GameConfig>().player.speed;
+    let expected = speed / 2.0 * ticks as f32 / FIXED_TIMESTEP_HZ as f32;
+    let moved = player_position(&mut app) - start;
+
+    // replays store the stick in 1/127 steps and the game plays that value
+    assert!((moved.x - expected).abs() < expected / 100.0,
+            "moved {} instead of {}", moved.x, expected);
+    assert_eq!(moved.y, 0.0);
+}
+
+#[test]
+fn dpad_moves_at_full_speed() {
+    let mut app = test_app();
+    let gamepad = connect_gamepad(&mut app);
+
+    press_key(&mut app, KeyCode::G);
+
+    let start = player_position(&mut app);
+    press_gamepad_button(&mut app, gamepad, GamepadButtonType::DPadUp);
+    let moved = player_position(&mut app) - start;
+
+    let speed = app.world.resource::<GameConfig>().player.speed;
+    assert!((moved.y - speed / FIXED_TIMESTEP_HZ as f32).abs() < 0.01,
+            "moved {} for one tick of d-pad", moved.y);
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use bevy::{prelude::*, ecs::event::ManualEventReader};
+
+use ball_game::events::{
+    PlaySound,
+    SoundEffect,
+    StarCollected,
+    EnemyBounced,
+    PlayerHit,
+};
+use ball_game::game::player::components::Player;
+use ball_game::game::enemy::components::Enemy;
+use ball_game::game::star::components::Star;
+use ball_game::game::score::resources::Score;
+use ball_game::game::stats::resources::RunStats;
+use ball_game::game::physics::components::{Position, Collider, CollisionLayer};
+
+use common::*;
+
+// everything sent since the reader last looked, events only live for two updates
+fn read<E: Event + Clone>(app: &App, reader: &mut ManualEventReader<E>) -> Vec<E> {
+    reader.read(app.world.resource::<Events<E>>()).cloned().collect()
+}
+
+fn run_stats(app: &App) -> &RunStats {
+    app.world.resource::<RunStats>()
+}
+
+#[test]
+fn the_enemies_a_run_starts_with_are_announced() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+
+    assert_eq!(run_stats(&app).enemies_spawned as usize, count::<Enemy>(&mut app));
+}
+
+#[test]
+fn collecting_a_star_is_scored_heard_and_counted() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+    let score = app.world.resource::<Score>().value;
+
+    let mut star_collected = ManualEventReader::<StarCollected>::default();
+    let mut play_sound = ManualEventReader::<PlaySound>::default();
+    let position = player_position(&mut app);
+    let star = app.world.spawn((
+        Position::new(position),
+        Star {},
+        Collider::aabb(Vec2::splat(15.0), CollisionLayer::STAR, CollisionLayer::NONE),
+    )).id();
+    update(&mut app, 1);
+
+    assert_eq!(read(&app, &mut star_collected),
+            vec![StarCollected { star, position }]);
+    assert!(read(&app, &mut play_sound).iter()
+            .any(|event| event.sound == SoundEffect::StarCollected));
+    assert_eq!(app.world.resource::<Score>().value, score + 1);
+    assert_eq!(run_stats(&app).stars_collected, 1);
+}
+
+#[test]
+fn an_enemy_landing_a_hit_is_reported() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+
+    let mut player_hit = ManualEventReader::<PlayerHit>::default();
+    let player_entity = app.world.query_filtered::<Entity, With<Player>>().single(&app.world);
+    let enemy_entity = collide_enemy_with_player(&mut app);
+    update(&mut app, 1);
+
+    let hits = read(&app, &mut player_hit);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].player, player_entity);
+    assert_eq!(hits[0].enemy, enemy_entity);
+    assert!(!hits[0].absorbed);
+    assert_eq!(run_stats(&app).hits_taken, 1);
+}
+
+#[test]
+fn bouncing_off_a_wall_has_no_other_enemy() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+
+    let mut enemy_bounced = ManualEventReader::<EnemyBounced>::default();
+    let player_position = player_position(&mut app);
+    let mut enemy_query = app.world.query::<(Entity, &mut Position, &mut Enemy)>();
+    let (enemy_entity, mut enemy_position, mut enemy) = enemy_query.iter_mut(&mut app.world)
+        .next()
+        .unwrap();
+    enemy_position.teleport(Vec2::new(0.0, player_position.y + 200.0));
+    enemy.direction = Vec2::NEG_X;
+    update(&mut app, 1);
+
+    let bounce = read(&app, &mut enemy_bounced).into_iter()
+        .find(|event| event.enemy == enemy_entity)
+        .expect("the enemy did not bounce");
+    assert_eq!(bounce.other, None);
+}
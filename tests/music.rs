@@ -0,0 +1,83 @@
+mod common;
+
+use bevy::prelude::*;
+
+use ball_game::AppState;
+use ball_game::music::components::MusicTrack;
+use ball_game::music::config::{MusicConfig, Playlists};
+use ball_game::music::resources::{MusicPlayer, Playlist};
+
+use common::*;
+
+// one second at the test's fixed update rate, plus a little
+const CROSSFADE_FRAMES: usize = 65;
+
+// the tracks never load, the fades run all the same
+fn music_app() -> App {
+    let mut app = test_app();
+
+    app.insert_resource(MusicConfig {
+        crossfade_seconds: 1.0,
+        paused_volume: 0.25,
+        playlists: Playlists {
+            main_menu: vec!["music/menu.ogg".to_string()],
+            game: vec!["music/game.ogg".to_string()],
+            game_over: Vec::new(),
+        },
+    });
+    // plays the main menu list with the config above
+    app.world.resource_mut::<MusicPlayer>().playlist = None;
+    app.update();
+
+    app
+}
+
+fn tracks(app: &mut App) -> Vec<(Playlist, bool)> {
+    app.world.query::<&MusicTrack>()
+        .iter(&app.world)
+        .map(|track| (track.playlist, track.fading_out))
+        .collect()
+}
+
+#[test]
+fn changing_state_crossfades_to_its_playlist() {
+    let mut app = music_app();
+    assert_eq!(tracks(&mut app), vec![(Playlist::MainMenu, false)]);
+    update(&mut app, CROSSFADE_FRAMES);
+
+    press_key(&mut app, KeyCode::G);
+    assert_eq!(app_state(&app), AppState::Game);
+    let mut playing = tracks(&mut app);
+    playing.sort_by_key(|(_, fading_out)| *fading_out);
+    assert_eq!(playing, vec![(Playlist::Game, false), (Playlist::MainMenu, true)]);
+
+    update(&mut app, CROSSFADE_FRAMES);
+    assert_eq!(tracks(&mut app), vec![(Playlist::Game, false)]);
+    let fade = app.world.query::<&MusicTrack>().single(&app.world).fade;
+    assert_eq!(fade, 1.0);
+}
+
+#[test]
+fn pausing_ducks_the_music() {
+    let mut app = music_app();
+
+    press_key(&mut app, KeyCode::G);
+    press_key(&mut app, KeyCode::Space);
+    update(&mut app, CROSSFADE_FRAMES);
+    assert_eq!(app.world.resource::<MusicPlayer>().duck, 0.25);
+
+    press_key(&mut app, KeyCode::Space);
+    update(&mut app, CROSSFADE_FRAMES);
+    assert_eq!(app.world.resource::<MusicPlayer>().duck, 1.0);
+}
+
+#[test]
+fn the_shipped_music_config_is_valid() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("assets")
+        .join(ball_game::music::config::MUSIC_CONFIG_PATH);
+
+    if let Err(error) = MusicConfig::load_from(&path) {
+        panic!("{}", error);
+    }
+}
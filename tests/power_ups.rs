@@ -0,0 +1,113 @@
+mod common;
+
+use bevy::prelude::*;
+
+use ball_game::game::config::GameConfig;
+use ball_game::game::player::components::Invulnerable;
+use ball_game::game::player::resources::Lives;
+use ball_game::game::enemy::components::Enemy;
+use ball_game::game::star::components::Star;
+use ball_game::game::score::resources::Score;
+use ball_game::game::physics::components::{Position, Collider, CollisionLayer};
+use ball_game::game::power_up::components::PowerUp;
+use ball_game::game::power_up::resources::{ActivePowerUps, PowerUpKind};
+
+use common::*;
+
+// drops a power-up on the player so the next fixed tick picks it up
+fn pick_up(app: &mut App, kind: PowerUpKind) {
+    let position = player_position(app);
+    app.world.spawn((Position::new(position), PowerUp { kind },
+                     Collider::circle(20.0, CollisionLayer::POWER_UP, CollisionLayer::NONE)));
+    update(app, 2);
+}
+
+fn active_power_ups(app: &App) -> &ActivePowerUps {
+    app.world.resource::<ActivePowerUps>()
+}
+
+#[test]
+fn a_shield_charge_absorbs_a_hit_instead_of_a_life() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+    let lives = app.world.resource::<Lives>().remaining;
+
+    pick_up(&mut app, PowerUpKind::Shield);
+    pick_up(&mut app, PowerUpKind::Shield);
+    assert_eq!(count::<PowerUp>(&mut app), 0);
+    assert_eq!(active_power_ups(&app).shield_charges, 2);
+
+    collide_enemy_with_player(&mut app);
+    update(&mut app, 2);
+
+    assert_eq!(app.world.resource::<Lives>().remaining, lives);
+    assert_eq!(active_power_ups(&app).shield_charges, 1);
+    assert_eq!(count::<Invulnerable>(&mut app), 1);
+}
+
+#[test]
+fn timed_power_ups_restart_on_pickup_and_run_out() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+    let config = app.world.resource::<GameConfig>().power_up.clone();
+
+    pick_up(&mut app, PowerUpKind::SlowMotion);
+    assert_eq!(active_power_ups(&app).enemy_speed_scale(&config),
+            config.slow_motion.enemy_speed);
+
+    update(&mut app, 60);
+    pick_up(&mut app, PowerUpKind::SlowMotion);
+    let remaining = active_power_ups(&app).timer(PowerUpKind::SlowMotion)
+        .unwrap()
+        .remaining_secs();
+    assert!(remaining > config.slow_motion.duration - 0.1
+                && remaining <= config.slow_motion.duration,
+            "a second pickup should restart the timer, not extend it");
+
+    update(&mut app, (config.slow_motion.duration * 60.0) as usize + 2);
+    assert!(!active_power_ups(&app).is_active(PowerUpKind::SlowMotion));
+    assert_eq!(active_power_ups(&app).enemy_speed_scale(&config), 1.0);
+}
+
+#[test]
+fn a_magnet_pulls_in_nearby_stars() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+    let score = app.world.resource::<Score>().value;
+
+    pick_up(&mut app, PowerUpKind::Magnet);
+    let near = player_position(&mut app) + Vec2::new(120.0, 0.0);
+    app.world.spawn((Position::new(near), Star {},
+                     Collider::circle(15.0, CollisionLayer::STAR, CollisionLayer::NONE)));
+    update(&mut app, 30);
+
+    assert!(app.world.resource::<Score>().value > score,
+            "the nearby star was never collected");
+}
+
+#[test]
+fn a_bomb_removes_the_nearest_enemies() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+    let bomb_count = app.world.resource::<GameConfig>().power_up.bomb.count;
+    let enemies = count::<Enemy>(&mut app);
+
+    pick_up(&mut app, PowerUpKind::Bomb);
+
+    assert_eq!(count::<Enemy>(&mut app), enemies.saturating_sub(bomb_count));
+    assert!(active_power_ups(&app).is_active(PowerUpKind::Bomb));
+}
+
+#[test]
+fn a_bomb_picked_up_as_an_enemy_hits_clears_it_first() {
+    let mut app = test_app();
+    press_key(&mut app, KeyCode::G);
+    let lives = app.world.resource::<Lives>().remaining;
+
+    let enemy_entity = collide_enemy_with_player(&mut app);
+    pick_up(&mut app, PowerUpKind::Bomb);
+
+    assert!(app.world.get_entity(enemy_entity).is_none());
+    assert_eq!(app.world.resource::<Lives>().remaining, lives);
+    assert_eq!(count::<Invulnerable>(&mut app), 0);
+}
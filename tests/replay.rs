@@ -0,0 +1,66 @@
+use bevy::prelude::Vec2;
+
+use ball_game::game::difficulty::Difficulty;
+use ball_game::game::replay::resources::*;
+
+fn replay(inputs: Vec<ReplayInput>) -> Replay {
+    Replay {
+        tick_hz: 60,
+        seed: 42,
+        difficulty: Difficulty::Hard,
+        arena: Vec2::new(1280.0, 720.0),
+        final_score: 7,
+        inputs,
+    }
+}
+
+#[test]
+fn analog_inputs_survive_a_round_trip() {
+    let inputs = [Vec2::new(0.5, -0.25), Vec2::ZERO, Vec2::new(1.0, 1.0)]
+        .iter()
+        .flat_map(|direction| std::iter::repeat(encode_input(*direction)).take(100))
+        .collect();
+    let replay = replay(inputs);
+
+    assert_eq!(Replay::decode(&replay.encode()).unwrap(), replay);
+}
+
+#[test]
+fn keyboard_directions_are_stored_exactly() {
+    let direction = Vec2::new(-1.0, 1.0);
+
+    assert_eq!(decode_input(encode_input(direction)), direction);
+}
+
+#[test]
+fn version_1_replays_still_load() {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(REPLAY_MAGIC);
+    bytes.push(1);
+    bytes.extend_from_slice(&60u16.to_le_bytes());
+    bytes.extend_from_slice(&42u64.to_le_bytes());
+    bytes.push(Difficulty::Hard.index());
+    bytes.extend_from_slice(&1280f32.to_le_bytes());
+    bytes.extend_from_slice(&720f32.to_le_bytes());
+    bytes.extend_from_slice(&7u32.to_le_bytes());
+    bytes.extend_from_slice(&3u32.to_le_bytes());
+    bytes.extend_from_slice(&2u32.to_le_bytes());
+    // up and right held for two ticks, then nothing
+    bytes.extend_from_slice(&[0b1001, 2, 0]);
+    bytes.extend_from_slice(&[0, 1, 0]);
+
+    let replay = Replay::decode(&bytes).unwrap();
+
+    assert_eq!(replay.inputs.iter().map(|input| decode_input(*input)).collect::<Vec<_>>(),
+            vec![Vec2::ONE, Vec2::ONE, Vec2::ZERO]);
+    assert_eq!(replay.final_score, 7);
+}
+
+#[test]
+fn unknown_versions_are_rejected() {
+    let mut bytes = replay(Vec::new()).encode();
+    bytes[REPLAY_MAGIC.len()] = REPLAY_VERSION + 1;
+
+    assert!(matches!(Replay::decode(&bytes),
+                    Err(ReplayError::UnsupportedVersion(_))));
+}
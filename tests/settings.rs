@@ -0,0 +1,78 @@
+mod common;
+
+use bevy::{prelude::*, window::{PrimaryWindow, WindowMode}};
+
+use ball_game::AppState;
+use ball_game::game::SimulationState;
+use ball_game::game::pause_menu::components::SettingsButton;
+use ball_game::settings::resources::*;
+use ball_game::settings_menu::components::*;
+
+use common::*;
+
+// there is no ui plugin in the tests, so the click is set by hand
+fn click<T: Component>(app: &mut App, matches: impl Fn(&T) -> bool) {
+    let mut button_query = app.world.query::<(&mut Interaction, &T)>();
+    let (mut interaction, _) = button_query.iter_mut(&mut app.world)
+        .find(|(_, button)| matches(button))
+        .expect("no such button");
+
+    *interaction = Interaction::Pressed;
+    update(app, 2);
+}
+
+fn start_game(app: &mut App) {
+    press_key(app, KeyCode::G);
+    assert_eq!(app_state(app), AppState::Game);
+    assert_eq!(simulation_state(app), SimulationState::Running);
+}
+
+#[test]
+fn settings_from_the_pause_menu_apply_and_persist() {
+    let mut app = test_app();
+
+    start_game(&mut app);
+    press_key(&mut app, KeyCode::Space);
+    assert_eq!(simulation_state(&app), SimulationState::Paused);
+
+    click::<SettingsButton>(&mut app, |_| true);
+    assert_eq!(count::<SettingsMenu>(&mut app), 1);
+
+    click::<VolumeStepButton>(&mut app, |button|
+        button.bus == VolumeBus::Master && button.step < 0.0);
+    click::<SettingButton>(&mut app, |button|
+        button.option == SettingOption::Fullscreen);
+
+    let settings = app.world.resource::<Settings>().clone();
+    assert_eq!(settings.master_volume, 0.9);
+    assert!(settings.fullscreen);
+    let window_mode = app.world.query_filtered::<&Window, With<PrimaryWindow>>()
+        .single(&app.world)
+        .mode;
+    assert_eq!(window_mode, WindowMode::BorderlessFullscreen);
+    assert_eq!(Settings::load(), settings);
+
+    // resuming closes the panel along with the pause menu
+    press_key(&mut app, KeyCode::Space);
+    assert_eq!(simulation_state(&app), SimulationState::Running);
+    assert_eq!(count::<SettingsMenu>(&mut app), 0);
+    assert!(!app.world.contains_resource::<SettingsMenuOpen>());
+}
+
+#[test]
+fn out_of_range_settings_from_disk_are_pulled_back() {
+    let path = std::env::temp_dir()
+        .join(format!("ball_game_settings_{}.ron", std::process::id()));
+    std::fs::write(&path, format!("(version: {}, settings: (master_volume: 5.0, \
+        sfx_volume: -1.0, resolution: (0, 0), vsync: false))", SETTINGS_FILE_VERSION))
+        .unwrap();
+
+    let settings = Settings::load_from(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(settings.master_volume, 1.0);
+    assert_eq!(settings.sfx_volume, 0.0);
+    assert_eq!(settings.resolution, RESOLUTIONS[0]);
+    // what was in range is kept
+    assert!(!settings.vsync);
+}
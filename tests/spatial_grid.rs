@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use ball_game::game::physics::resources::SpatialGrid;
+
+struct Ball {
+    entity: Entity,
+    position: Vec2,
+    radius: f32,
+}
+
+fn random_balls(count: u32, size: f32) -> Vec<Ball> {
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+
+    (0..count).map(|index| Ball {
+        entity: Entity::from_raw(index),
+        // a few outside the arena, the grid has no bounds
+        position: Vec2::new(rng.gen_range(-50.0..size), rng.gen_range(-50.0..size)),
+        radius: rng.gen_range(2.0..60.0),
+    }).collect()
+}
+
+#[test]
+fn queries_match_checking_every_entity() {
+    let balls = random_balls(2000, 1000.0);
+    let mut grid = SpatialGrid::new(64.0);
+    balls.iter().for_each(|ball| grid.insert(ball.entity, ball.position, ball.radius));
+    assert_eq!(grid.len(), balls.len());
+
+    let points = random_balls(200, 1000.0);
+    points.iter().for_each(|point| {
+        let mut found: Vec<Entity> = grid.query(point.position, point.radius)
+            .map(|entry| entry.entity)
+            .collect();
+        let mut expected: Vec<Entity> = balls.iter()
+            .filter(|ball| ball.position.distance(point.position) < point.radius + ball.radius)
+            .map(|ball| ball.entity)
+            .collect();
+        found.sort();
+        expected.sort();
+
+        assert_eq!(found, expected);
+    });
+}
+
+#[test]
+fn clearing_empties_the_grid() {
+    let mut grid = SpatialGrid::new(64.0);
+    grid.insert(Entity::from_raw(0), Vec2::ZERO, 10.0);
+    grid.clear();
+
+    assert!(grid.is_empty());
+    assert_eq!(grid.query(Vec2::ZERO, 100.0).count(), 0);
+}
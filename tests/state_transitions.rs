@@ -0,0 +1,209 @@
+mod common;
+
+use bevy::{prelude::*, app::AppExit, window::WindowFocused};
+
+use ball_game::AppState;
+use ball_game::game::SimulationState;
+use ball_game::game::player::components::{Invulnerable, Player};
+use ball_game::game::player::resources::Lives;
+use ball_game::game::enemy::components::Enemy;
+use ball_game::game::star::components::Star;
+use ball_game::game::physics::components::Position;
+use ball_game::game::hud::components::HUD;
+use ball_game::game::pause_menu::components::PauseMenu;
+use ball_game::game::game_over_menu::components::GameOverMenu;
+use ball_game::game::score::resources::{Score, PendingHighScore, HighScores};
+use ball_game::game::difficulty::Difficulty;
+use ball_game::main_menu::components::MainMenu;
+use ball_game::settings::resources::Settings;
+
+use common::*;
+
+fn assert_game_running(app: &mut App) {
+    assert_eq!(app_state(app), AppState::Game);
+    assert_eq!(count::<Player>(app), 1);
+    assert!(count::<Enemy>(app) > 0, "no enemies were spawned");
+    assert!(count::<Star>(app) > 0, "no stars were spawned");
+    assert_eq!(count::<HUD>(app), 1);
+    assert_eq!(count::<MainMenu>(app), 0);
+    assert_eq!(count::<GameOverMenu>(app), 0);
+    assert!(app.world.contains_resource::<Score>());
+}
+
+// everything OnExit(AppState::Game) is responsible for
+fn assert_game_cleaned_up(app: &mut App) {
+    assert_ne!(app_state(app), AppState::Game);
+    assert_eq!(count::<Player>(app), 0, "player left behind");
+    assert_eq!(count::<Enemy>(app), 0, "enemies left behind");
+    assert_eq!(count::<Star>(app), 0, "stars left behind");
+    assert_eq!(count::<HUD>(app), 0, "hud left behind");
+    assert_eq!(count::<PauseMenu>(app), 0, "pause menu left behind");
+    assert!(!app.world.contains_resource::<Score>(), "score left behind");
+}
+
+fn start_game_from_main_menu(app: &mut App) {
+    assert_eq!(app_state(app), AppState::MainMenu);
+    assert_eq!(count::<MainMenu>(app), 1);
+
+    press_key(app, KeyCode::G);
+
+    assert_game_running(app);
+}
+
+// a qualifying score waits for a name before the game over screen takes keys
+fn confirm_pending_high_score(app: &mut App) {
+    if app.world.contains_resource::<PendingHighScore>() {
+        press_key(app, KeyCode::Return);
+    }
+
+    assert!(!app.world.contains_resource::<PendingHighScore>());
+}
+
+#[test]
+fn main_menu_to_game_to_game_over_and_back() {
+    let mut app = test_app();
+
+    start_game_from_main_menu(&mut app);
+
+    // Game -> Paused -> Game
+    press_key(&mut app, KeyCode::Space);
+    assert_eq!(simulation_state(&app), SimulationState::Paused);
+    assert_eq!(count::<PauseMenu>(&mut app), 1);
+
+    let paused_position = player_position(&mut app);
+    press_key(&mut app, KeyCode::Right);
+    assert_eq!(player_position(&mut app), paused_position,
+            "the player moved while paused");
+
+    press_key(&mut app, KeyCode::Space);
+    assert_eq!(simulation_state(&app), SimulationState::Running);
+    assert_eq!(count::<PauseMenu>(&mut app), 0);
+    assert_game_running(&mut app);
+
+    // Game -> GameOver, on the last life
+    app.world.resource_mut::<Lives>().remaining = 1;
+    collide_enemy_with_player(&mut app);
+    update(&mut app, 3);
+
+    assert_eq!(app_state(&app), AppState::GameOver);
+    assert_game_cleaned_up(&mut app);
+    assert_eq!(count::<GameOverMenu>(&mut app), 1);
+
+    // GameOver -> Game
+    confirm_pending_high_score(&mut app);
+    press_key(&mut app, KeyCode::G);
+
+    assert_game_running(&mut app);
+    assert_eq!(app.world.resource::<Score>().value, 0,
+            "score carried over from the last run");
+}
+
+#[test]
+fn quitting_during_name_entry_keeps_the_high_score() {
+    let mut app = test_app();
+
+    start_game_from_main_menu(&mut app);
+    app.world.resource_mut::<Lives>().remaining = 1;
+    collide_enemy_with_player(&mut app);
+    update(&mut app, 3);
+    assert_eq!(app_state(&app), AppState::GameOver);
+
+    // the table on disk may already be full from other tests
+    app.world.insert_resource(PendingHighScore {
+        score: u32::MAX,
+        name: "QUITTER".to_string(),
+        difficulty: Difficulty::default(),
+    });
+    app.world.send_event(AppExit);
+    update(&mut app, 1);
+
+    assert!(!app.world.contains_resource::<PendingHighScore>());
+    assert!(app.world.resource::<HighScores>().scores.iter()
+                .any(|entry| entry.name == "QUITTER" && entry.score == u32::MAX),
+            "the pending score was dropped");
+}
+
+#[test]
+fn leaving_a_paused_game_cleans_up() {
+    let mut app = test_app();
+
+    start_game_from_main_menu(&mut app);
+    press_key(&mut app, KeyCode::Space);
+    assert_eq!(count::<PauseMenu>(&mut app), 1);
+
+    press_key(&mut app, KeyCode::M);
+
+    assert_eq!(app_state(&app), AppState::MainMenu);
+    assert_game_cleaned_up(&mut app);
+    assert_eq!(simulation_state(&app), SimulationState::Running);
+    assert_eq!(count::<MainMenu>(&mut app), 1);
+
+    start_game_from_main_menu(&mut app);
+}
+
+#[test]
+fn star_pickup_scores_and_score_resets_next_run() {
+    let mut app = test_app();
+
+    start_game_from_main_menu(&mut app);
+
+    let player_position = player_position(&mut app);
+    let mut star_query = app.world.query_filtered::<(Entity, &mut Position), With<Star>>();
+    let (star, mut star_position) = star_query.iter_mut(&mut app.world).next().unwrap();
+    star_position.current = player_position;
+    update(&mut app, 1);
+
+    assert_eq!(app.world.resource::<Score>().value, 1);
+    assert!(app.world.get_entity(star).is_none(), "collected star was not despawned");
+
+    press_key(&mut app, KeyCode::M);
+    assert_game_cleaned_up(&mut app);
+
+    start_game_from_main_menu(&mut app);
+    assert_eq!(app.world.resource::<Score>().value, 0);
+}
+
+#[test]
+fn a_hit_costs_a_life_and_respawns_the_player_clear_of_enemies() {
+    let mut app = test_app();
+
+    start_game_from_main_menu(&mut app);
+    let lives = app.world.resource::<Lives>().remaining;
+    assert!(lives > 1);
+
+    collide_enemy_with_player(&mut app);
+    update(&mut app, 2);
+
+    assert_eq!(app_state(&app), AppState::Game);
+    assert_eq!(app.world.resource::<Lives>().remaining, lives - 1);
+    assert_eq!(count::<Invulnerable>(&mut app), 1);
+    let respawn_position = player_position(&mut app);
+    let mut enemy_query = app.world.query_filtered::<&Position, With<Enemy>>();
+    assert!(enemy_query.iter(&app.world)
+                .all(|enemy| enemy.current.distance(respawn_position) > 64.0),
+            "respawned on top of an enemy");
+
+    // enemies pass through while the player blinks
+    collide_enemy_with_player(&mut app);
+    update(&mut app, 2);
+    assert_eq!(app.world.resource::<Lives>().remaining, lives - 1);
+}
+
+// kept away from tests/settings.rs, the settings file it saves would race them
+#[test]
+fn losing_focus_pauses_only_when_enabled() {
+    let mut app = test_app();
+    let window = app.world.resource::<TestWindow>().0;
+
+    press_key(&mut app, KeyCode::G);
+    assert_eq!(simulation_state(&app), SimulationState::Running);
+    app.world.send_event(WindowFocused { window, focused: false });
+    update(&mut app, 2);
+    assert_eq!(simulation_state(&app), SimulationState::Paused);
+
+    press_key(&mut app, KeyCode::Space);
+    app.world.resource_mut::<Settings>().pause_on_focus_loss = false;
+    app.world.send_event(WindowFocused { window, focused: false });
+    update(&mut app, 2);
+    assert_eq!(simulation_state(&app), SimulationState::Running);
+}